@@ -1,17 +1,18 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
     http::client::{Client, ClientResult},
-    time::timestamp,
-    types::{Asset, Commission, Decimal, Quantity, Symbol},
+    types::{Asset, Commission, Decimal, Quantity, Symbol, Timestamp},
 };
 
 impl Client {
     pub async fn user_asset(
         &self,
-        asset: Option<&String>,
+        asset: Option<&Asset>,
         need_btc_valuation: Option<bool>,
-        recv_window: Option<u8>,
+        recv_window: Option<Duration>,
     ) -> ClientResult<Vec<UserAsset>> {
         let mut url = self.base_url()?;
         url.set_path("/sapi/v3/asset/getUserAsset");
@@ -26,35 +27,24 @@ impl Client {
             if let Some(value) = need_btc_valuation {
                 query_pairs.append_pair("needBtcValuation", &value.to_string());
             }
-
-            if let Some(value) = recv_window {
-                query_pairs.append_pair("recvWindow", &value.to_string());
-            }
-
-            query_pairs.append_pair("timestamp", &timestamp().as_millis().to_string());
         }
 
         self.build_sign_request_post(url)?
+            .with_recv_window(recv_window)
             .with_api_key(self.secret.api_key()?)
             .send()
             .await
     }
 
-    pub async fn api_restrictions(&self, recv_window: Option<u8>) -> ClientResult<ApiRestrictions> {
+    pub async fn api_restrictions(
+        &self,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<ApiRestrictions> {
         let mut url = self.base_url()?;
         url.set_path("/sapi/v1/account/apiRestrictions");
 
-        {
-            let mut query_pairs = url.query_pairs_mut();
-
-            if let Some(value) = recv_window {
-                query_pairs.append_pair("recvWindow", &value.to_string());
-            }
-
-            query_pairs.append_pair("timestamp", &timestamp().as_millis().to_string());
-        }
-
         self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
             .with_api_key(self.secret.api_key()?)
             .send()
             .await
@@ -63,7 +53,7 @@ impl Client {
     pub async fn spot_account(
         &self,
         omit_zero_balances: Option<bool>,
-        recv_window: Option<u8>,
+        recv_window: Option<Duration>,
     ) -> ClientResult<SpotAccount> {
         let mut url = self.base_url()?;
         url.set_path("/api/v3/account");
@@ -74,15 +64,11 @@ impl Client {
             if let Some(value) = omit_zero_balances {
                 query_pairs.append_pair("omitZeroBalances", &value.to_string());
             }
-
-            if let Some(value) = recv_window {
-                query_pairs.append_pair("recvWindow", &value.to_string());
-            }
-
-            query_pairs.append_pair("timestamp", &timestamp().as_millis().to_string());
         }
 
         self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(20)
             .with_api_key(self.secret.api_key()?)
             .send()
             .await
@@ -96,10 +82,10 @@ impl Client {
             let mut query_pairs = url.query_pairs_mut();
 
             query_pairs.append_pair("symbol", symbol);
-            query_pairs.append_pair("timestamp", &timestamp().as_millis().to_string());
         }
 
         self.build_sign_request_get(url)?
+            .with_weight(20)
             .with_api_key(self.secret.api_key()?)
             .send()
             .await
@@ -108,7 +94,7 @@ impl Client {
     pub async fn trade_fee(
         &self,
         symbol: &Symbol,
-        recv_window: Option<u8>,
+        recv_window: Option<Duration>,
     ) -> ClientResult<Vec<TradeFee>> {
         let mut url = self.base_url()?;
         url.set_path("/sapi/v1/asset/tradeFee");
@@ -116,15 +102,11 @@ impl Client {
         {
             let mut query_pairs = url.query_pairs_mut();
 
-            if let Some(value) = recv_window {
-                query_pairs.append_pair("recvWindow", &value.to_string());
-            }
-
             query_pairs.append_pair("symbol", symbol);
-            query_pairs.append_pair("timestamp", &timestamp().as_millis().to_string());
         }
 
         self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
             .with_api_key(self.secret.api_key()?)
             .send()
             .await
@@ -161,7 +143,7 @@ pub struct ApiRestrictions {
     pub ip_restrict: bool,
 
     #[serde(rename = "createTime")]
-    pub create_time: u128,
+    pub create_time: Timestamp,
 
     #[serde(rename = "enableInternalTransfer")]
     pub enable_internal_transfer: bool,
@@ -226,7 +208,7 @@ pub struct SpotAccount {
     pub prevent_sor: bool,
 
     #[serde(rename = "updateTime")]
-    pub update_time: u64,
+    pub update_time: Timestamp,
 
     #[serde(rename = "accountType")]
     pub account_type: String,
@@ -315,12 +297,18 @@ mod tests {
     #[tokio::test]
     async fn test_spot_commission() {
         let client = client_with_key_secret();
-        client.spot_commission(&"BTCUSDT".into()).await.unwrap();
+        client
+            .spot_commission(&"BTCUSDT".parse().unwrap())
+            .await
+            .unwrap();
     }
 
     #[tokio::test]
     async fn test_trade_fee() {
         let client = client_with_key_secret();
-        client.trade_fee(&"BTCUSDT".into(), None).await.unwrap();
+        client
+            .trade_fee(&"BTCUSDT".parse().unwrap(), None)
+            .await
+            .unwrap();
     }
 }
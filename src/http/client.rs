@@ -5,7 +5,12 @@ use reqwest::header::HeaderMap;
 use reqwest::Client as RequestClient;
 
 use super::error::ClientError;
+use super::limit::{BackoffPolicy, RateLimiter};
 use super::request::Secret;
+use super::retry::RetryPolicy;
+use super::sign::{Signer, SigningKey};
+
+use crate::time::{recv_window_param, ClockSync, TimeUnit};
 
 const DEFAULT_BASE_URL: &str = "https://api.binance.com";
 
@@ -15,12 +20,33 @@ pub struct Client {
     pub(crate) base_url: String,
     pub(crate) inner: RequestClient,
     pub(crate) secret: Secret,
+    pub(crate) clock_sync: Option<ClockSync>,
+    pub(crate) recv_window: Option<Duration>,
+    pub(crate) rate_limiter: RateLimiter,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    pub(crate) time_unit: TimeUnit,
 }
 
 impl Client {
     pub fn builder() -> ClientBuilder {
         ClientBuilder::new()
     }
+
+    pub fn recv_window(&self) -> Option<Duration> {
+        self.recv_window
+    }
+
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    pub fn time_unit(&self) -> TimeUnit {
+        self.time_unit
+    }
 }
 
 pub struct ClientBuilder {
@@ -28,6 +54,12 @@ pub struct ClientBuilder {
     secret: Secret,
     timeout: Duration,
     header: HeaderMap,
+    clock_sync: Option<ClockSync>,
+    recv_window: Option<Duration>,
+    throttle: bool,
+    backoff_policy: BackoffPolicy,
+    retry_policy: Option<RetryPolicy>,
+    time_unit: TimeUnit,
 }
 
 impl Default for ClientBuilder {
@@ -43,6 +75,12 @@ impl Default for ClientBuilder {
             secret: Secret::default(),
             timeout: Duration::from_secs(300),
             header: default_header,
+            clock_sync: None,
+            recv_window: None,
+            throttle: false,
+            backoff_policy: BackoffPolicy::default(),
+            retry_policy: None,
+            time_unit: TimeUnit::default(),
         }
     }
 }
@@ -52,7 +90,16 @@ impl ClientBuilder {
         Self::default()
     }
 
-    pub fn build(self) -> Result<Client, Box<dyn Error>> {
+    pub fn build(mut self) -> Result<Client, Box<dyn Error>> {
+        if let Some(value) = self.recv_window {
+            recv_window_param(value)?;
+        }
+
+        if self.time_unit == TimeUnit::Microsecond {
+            let value = self.time_unit.as_str().parse()?;
+            self.header.insert("X-MBX-TIME-UNIT", value);
+        }
+
         let client = RequestClient::builder()
             .connect_timeout(self.timeout)
             .default_headers(self.header);
@@ -61,6 +108,11 @@ impl ClientBuilder {
             inner: client.build()?,
             secret: self.secret,
             base_url: self.base_url,
+            clock_sync: self.clock_sync,
+            recv_window: self.recv_window,
+            rate_limiter: RateLimiter::new(self.throttle, self.backoff_policy),
+            retry_policy: self.retry_policy,
+            time_unit: self.time_unit,
         };
 
         Ok(client)
@@ -89,10 +141,69 @@ impl ClientBuilder {
 
         self
     }
+
+    pub fn set_signing_key(self, value: SigningKey) -> Self {
+        self.set_signer(value)
+    }
+
+    pub fn set_signer<S>(mut self, value: S) -> Self
+    where
+        S: Signer + 'static,
+    {
+        self.secret.update_signer(value);
+
+        self
+    }
+
+    // Stamps signed requests with the server time instead of the local time
+    pub fn set_clock_sync(mut self, value: ClockSync) -> Self {
+        self.clock_sync = Some(value);
+
+        self
+    }
+
+    // Default recvWindow of signed requests, a per-call value takes precedence
+    pub fn set_recv_window(mut self, value: Duration) -> Self {
+        self.recv_window = Some(value);
+
+        self
+    }
+
+    // Waits for the next window instead of sending a request that would go
+    // over a limit loaded from `exchange_info`
+    pub fn set_throttle(mut self, value: bool) -> Self {
+        self.throttle = value;
+
+        self
+    }
+
+    pub fn set_backoff_policy(mut self, value: BackoffPolicy) -> Self {
+        self.backoff_policy = value;
+
+        self
+    }
+
+    // Requests are sent once when not set
+    pub fn set_retry_policy(mut self, value: RetryPolicy) -> Self {
+        self.retry_policy = Some(value);
+
+        self
+    }
+
+    // Asks for times in microseconds, which `Timestamp` keeps track of
+    pub fn set_time_unit(mut self, value: TimeUnit) -> Self {
+        self.time_unit = value;
+
+        self
+    }
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::task::JoinHandle;
+
     use super::Client;
 
     fn dotenv() {
@@ -123,4 +234,58 @@ pub(crate) mod tests {
             .build()
             .unwrap()
     }
+
+    pub(crate) type MockHeaders = Vec<(&'static str, &'static str)>;
+
+    pub(crate) async fn mock_server(
+        responses: Vec<(u16, String)>,
+    ) -> (String, JoinHandle<Vec<String>>) {
+        let responses = responses
+            .into_iter()
+            .map(|(status, body)| (status, Vec::new(), body))
+            .collect();
+
+        mock_server_with_headers(responses).await
+    }
+
+    // Serves the canned `(status, headers, body)` responses in order, one
+    // per connection, and resolves to the request lines it received
+    pub(crate) async fn mock_server_with_headers(
+        responses: Vec<(u16, MockHeaders, String)>,
+    ) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = tokio::spawn(async move {
+            let mut requests = Vec::new();
+
+            for (status, headers, body) in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+
+                let mut buf = vec![0; 8192];
+                let len = stream.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..len]);
+                requests.push(request.lines().next().unwrap_or_default().to_string());
+
+                let headers: String = headers
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}\r\n", name, value))
+                    .collect();
+
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    headers,
+                    body
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+                stream.shutdown().await.ok();
+            }
+
+            requests
+        });
+
+        (base_url, handle)
+    }
 }
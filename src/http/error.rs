@@ -1,61 +1,287 @@
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
+use std::time::Duration;
 
+use reqwest::header::HeaderMap;
 use reqwest::Error as RequestError;
 use serde::{Deserialize, Serialize};
 use serde_json::error::Error as SerdeJsonError;
 use url::ParseError as UrlParseError;
 
+use super::error_code::BinanceErrorCode;
+
+use crate::time::TimeUnit;
+
 #[derive(Debug)]
 pub enum ClientError {
     Authorization(String),
-    SerdeJson(String),
-    UrlParse(String),
-    Request(String),
-    Binance(BinanceError),
+    InvalidParameter(String),
+    // Failures of the client itself, such as the random generator
+    Internal(String),
+    UrlParse(UrlParseError),
+    // Serializing request parameters
+    SerdeJson(SerdeJsonError),
+    // Connecting, sending or reading the response
+    Request(RequestError),
+    // The response body did not match the expected type
+    Decode(SerdeJsonError, Box<HttpResponse>),
+    // A failure status with a body that is not a Binance error
+    Http(Box<HttpResponse>),
+    Binance(BinanceError, Box<HttpResponse>),
+    // HTTP 429, the request weight or order count limit was exceeded
+    RateLimited(RateLimitError),
+    // HTTP 418, the IP was banned after repeated 429s
+    Banned(RateLimitError),
+}
+
+impl ClientError {
+    // The response that caused the error, if one was received
+    pub fn response(&self) -> Option<&HttpResponse> {
+        match self {
+            Self::Decode(_, response) => Some(response),
+            Self::Http(response) => Some(response),
+            Self::Binance(_, response) => Some(response),
+            Self::RateLimited(e) => e.response(),
+            Self::Banned(e) => e.response(),
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Request(e) => e.status().map(|v| v.as_u16()),
+            Self::RateLimited(e) => Some(e.status()),
+            Self::Banned(e) => Some(e.status()),
+            _ => self.response().map(|v| v.status()),
+        }
+    }
+
+    pub fn binance_error(&self) -> Option<&BinanceError> {
+        match self {
+            Self::Binance(e, _) => Some(e),
+            Self::RateLimited(e) => e.binance_error(),
+            Self::Banned(e) => e.binance_error(),
+            _ => None,
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::UrlParse(e) => Some(e),
+            Self::SerdeJson(e) => Some(e),
+            Self::Request(e) => Some(e),
+            Self::Decode(e, _) => Some(e),
+            Self::Binance(e, _) => Some(e),
+            Self::RateLimited(e) | Self::Banned(e) => {
+                e.binance_error().map(|v| v as &(dyn Error + 'static))
+            }
+            _ => None,
+        }
+    }
 }
 
-impl Error for ClientError {}
 impl Display for ClientError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        let message = match self {
-            Self::Authorization(e) => e.to_string(),
-            Self::SerdeJson(e) => e.to_string(),
-            Self::UrlParse(e) => e.to_string(),
-            Self::Request(e) => e.to_string(),
-            Self::Binance(e) => e.to_string(),
-        };
-
-        write!(f, "{}", message)
+        match self {
+            Self::Authorization(e) => write!(f, "{}", e),
+            Self::InvalidParameter(e) => write!(f, "{}", e),
+            Self::Internal(e) => write!(f, "{}", e),
+            Self::UrlParse(e) => write!(f, "{}", e),
+            Self::SerdeJson(e) => write!(f, "{}", e),
+            Self::Request(e) => write!(f, "{}", e),
+            Self::Decode(e, response) => write!(f, "{}: {}", response, e),
+            Self::Http(response) => write!(f, "{}: {}", response, response.body()),
+            Self::Binance(e, _) => write!(f, "{}", e),
+            Self::RateLimited(e) => write!(f, "{}", e),
+            Self::Banned(e) => write!(f, "{}", e),
+        }
     }
 }
 
 impl From<RequestError> for ClientError {
     fn from(value: RequestError) -> Self {
-        Self::Request(value.to_string())
+        Self::Request(value)
     }
 }
 
 impl From<UrlParseError> for ClientError {
     fn from(value: UrlParseError) -> Self {
-        Self::UrlParse(value.to_string())
+        Self::UrlParse(value)
     }
 }
 
 impl From<SerdeJsonError> for ClientError {
     fn from(value: SerdeJsonError) -> Self {
-        Self::SerdeJson(value.to_string())
+        Self::SerdeJson(value)
+    }
+}
+
+// A response read in full, kept on errors to tell what Binance replied
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    status: u16,
+    path: String,
+    headers: HeaderMap,
+    body: String,
+    time_unit: TimeUnit,
+}
+
+impl HttpResponse {
+    pub(crate) fn new(status: u16, path: String, headers: HeaderMap, body: String) -> Self {
+        Self {
+            status,
+            path,
+            headers,
+            body,
+            time_unit: TimeUnit::default(),
+        }
+    }
+
+    pub(crate) fn with_time_unit(mut self, value: TimeUnit) -> Self {
+        self.time_unit = value;
+
+        self
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    // Path of the endpoint, without the query which holds the signature
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    // Unit of the times in the body, as requested with `X-MBX-TIME-UNIT`
+    pub fn time_unit(&self) -> TimeUnit {
+        self.time_unit
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl Display for HttpResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "HTTP {} {}", self.status, self.path)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BinanceError {
     code: i64,
     msg: String,
 }
 
+impl BinanceError {
+    pub fn code(&self) -> i64 {
+        self.code
+    }
+
+    pub fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    pub fn error_code(&self) -> BinanceErrorCode {
+        BinanceErrorCode::new(self.code, &self.msg)
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.error_code().is_retryable()
+    }
+
+    // Ban expiry out of "IP(...) banned until 1659146373035. Please use ..."
+    pub(crate) fn banned_until(&self) -> Option<Duration> {
+        let (_, value) = self.msg.split_once("banned until ")?;
+        let value = value.split(|c: char| !c.is_ascii_digit()).next()?;
+
+        Some(Duration::from_millis(value.parse().ok()?))
+    }
+}
+
+impl Error for BinanceError {}
 impl Display for BinanceError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "{} {}", self.code, self.msg)
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct RateLimitError {
+    status: u16,
+    retry_after: Option<Duration>,
+    banned_until: Option<Duration>,
+    error: Option<BinanceError>,
+    response: Option<Box<HttpResponse>>,
+}
+
+impl RateLimitError {
+    pub(crate) fn new(
+        status: u16,
+        retry_after: Option<Duration>,
+        banned_until: Option<Duration>,
+        error: Option<BinanceError>,
+        response: Option<HttpResponse>,
+    ) -> Self {
+        Self {
+            status,
+            retry_after,
+            banned_until,
+            error,
+            response: response.map(Box::new),
+        }
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    // Value of the `Retry-After` header
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after
+    }
+
+    // Time since UNIX EPOCH at which the ban is lifted
+    pub fn banned_until(&self) -> Option<Duration> {
+        self.banned_until
+    }
+
+    pub fn binance_error(&self) -> Option<&BinanceError> {
+        self.error.as_ref()
+    }
+
+    // `None` when the request was refused without being sent
+    pub fn response(&self) -> Option<&HttpResponse> {
+        self.response.as_deref()
+    }
+}
+
+impl Display for RateLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "HTTP {}", self.status)?;
+
+        if let Some(value) = self.retry_after {
+            write!(f, ", retry after {}s", value.as_secs())?;
+        }
+
+        if let Some(value) = self.banned_until {
+            write!(f, ", banned until {}", value.as_millis())?;
+        }
+
+        match &self.error {
+            Some(value) => write!(f, ": {}", value),
+            None => Ok(()),
+        }
+    }
+}
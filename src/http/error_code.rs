@@ -0,0 +1,405 @@
+// Error codes of the spot API, see
+// https://developers.binance.com/docs/binance-spot-api-docs/errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BinanceErrorCode {
+    // 10xx - General Server or Network issues
+    UnknownError,
+    Disconnected,
+    Unauthorized,
+    TooManyRequests,
+    UnexpectedResponse,
+    Timeout,
+    ServerBusy,
+    InvalidMessage,
+    UnknownOrderComposition,
+    TooManyOrders,
+    ServiceShuttingDown,
+    UnsupportedOperation,
+    InvalidTimestamp,
+    InvalidSignature,
+
+    // 11xx - Request issues
+    IllegalChars,
+    TooManyParameters,
+    MandatoryParamEmptyOrMalformed,
+    UnknownParam,
+    UnreadParameters,
+    ParamEmpty,
+    ParamNotRequired,
+    ParamOverflow,
+    BadPrecision,
+    NoDepth,
+    TimeInForceNotRequired,
+    InvalidTimeInForce,
+    InvalidOrderType,
+    InvalidSide,
+    EmptyNewClientOrderId,
+    EmptyOrigClientOrderId,
+    BadInterval,
+    BadSymbol,
+    InvalidSymbolStatus,
+    InvalidListenKey,
+    MoreThanXxHours,
+    OptionalParamsBadCombo,
+    InvalidParameter,
+    BadStrategyType,
+    InvalidJson,
+    InvalidTickerType,
+    InvalidCancelRestrictions,
+    DuplicateSymbols,
+    OcoOrderTypeRejected,
+    OcoIcebergQtyTimeInForce,
+    BuyOcoLimitMustBeBelow,
+    SellOcoLimitMustBeAbove,
+    BothOcoOrdersCannotBeLimit,
+    InvalidTimeUnit,
+    BuyOcoStopLossMustBeAbove,
+    SellOcoStopLossMustBeBelow,
+    BuyOcoTakeProfitMustBeBelow,
+    SellOcoTakeProfitMustBeAbove,
+
+    // 20xx - Processing issues
+    NewOrderRejected(RejectReason),
+    CancelRejected(RejectReason),
+    NoSuchOrder,
+    BadApiKeyFormat,
+    RejectedApiKey,
+    NoTradingWindow,
+    CancelReplacePartiallyFailed,
+    CancelReplaceFailed,
+    OrderArchived,
+    OrderAmendRejected(RejectReason),
+
+    // Codes this crate does not know of yet
+    Unknown(i64),
+}
+
+impl BinanceErrorCode {
+    // The message refines -2010, -2011 and -2038 into a `RejectReason`
+    pub fn new(code: i64, msg: &str) -> Self {
+        match code {
+            -1000 => Self::UnknownError,
+            -1001 => Self::Disconnected,
+            -1002 => Self::Unauthorized,
+            -1003 => Self::TooManyRequests,
+            -1006 => Self::UnexpectedResponse,
+            -1007 => Self::Timeout,
+            -1008 => Self::ServerBusy,
+            -1013 => Self::InvalidMessage,
+            -1014 => Self::UnknownOrderComposition,
+            -1015 => Self::TooManyOrders,
+            -1016 => Self::ServiceShuttingDown,
+            -1020 => Self::UnsupportedOperation,
+            -1021 => Self::InvalidTimestamp,
+            -1022 => Self::InvalidSignature,
+
+            -1100 => Self::IllegalChars,
+            -1101 => Self::TooManyParameters,
+            -1102 => Self::MandatoryParamEmptyOrMalformed,
+            -1103 => Self::UnknownParam,
+            -1104 => Self::UnreadParameters,
+            -1105 => Self::ParamEmpty,
+            -1106 => Self::ParamNotRequired,
+            -1108 => Self::ParamOverflow,
+            -1111 => Self::BadPrecision,
+            -1112 => Self::NoDepth,
+            -1114 => Self::TimeInForceNotRequired,
+            -1115 => Self::InvalidTimeInForce,
+            -1116 => Self::InvalidOrderType,
+            -1117 => Self::InvalidSide,
+            -1118 => Self::EmptyNewClientOrderId,
+            -1119 => Self::EmptyOrigClientOrderId,
+            -1120 => Self::BadInterval,
+            -1121 => Self::BadSymbol,
+            -1122 => Self::InvalidSymbolStatus,
+            -1125 => Self::InvalidListenKey,
+            -1127 => Self::MoreThanXxHours,
+            -1128 => Self::OptionalParamsBadCombo,
+            -1130 => Self::InvalidParameter,
+            -1134 => Self::BadStrategyType,
+            -1135 => Self::InvalidJson,
+            -1139 => Self::InvalidTickerType,
+            -1145 => Self::InvalidCancelRestrictions,
+            -1151 => Self::DuplicateSymbols,
+            -1158 => Self::OcoOrderTypeRejected,
+            -1160 => Self::OcoIcebergQtyTimeInForce,
+            -1165 => Self::BuyOcoLimitMustBeBelow,
+            -1166 => Self::SellOcoLimitMustBeAbove,
+            -1168 => Self::BothOcoOrdersCannotBeLimit,
+            -1194 => Self::InvalidTimeUnit,
+            -1196 => Self::BuyOcoStopLossMustBeAbove,
+            -1197 => Self::SellOcoStopLossMustBeBelow,
+            -1198 => Self::BuyOcoTakeProfitMustBeBelow,
+            -1199 => Self::SellOcoTakeProfitMustBeAbove,
+
+            -2010 => Self::NewOrderRejected(RejectReason::new(msg)),
+            -2011 => Self::CancelRejected(RejectReason::new(msg)),
+            -2013 => Self::NoSuchOrder,
+            -2014 => Self::BadApiKeyFormat,
+            -2015 => Self::RejectedApiKey,
+            -2016 => Self::NoTradingWindow,
+            -2021 => Self::CancelReplacePartiallyFailed,
+            -2022 => Self::CancelReplaceFailed,
+            -2026 => Self::OrderArchived,
+            -2038 => Self::OrderAmendRejected(RejectReason::new(msg)),
+
+            value => Self::Unknown(value),
+        }
+    }
+
+    pub fn code(&self) -> i64 {
+        match self {
+            Self::UnknownError => -1000,
+            Self::Disconnected => -1001,
+            Self::Unauthorized => -1002,
+            Self::TooManyRequests => -1003,
+            Self::UnexpectedResponse => -1006,
+            Self::Timeout => -1007,
+            Self::ServerBusy => -1008,
+            Self::InvalidMessage => -1013,
+            Self::UnknownOrderComposition => -1014,
+            Self::TooManyOrders => -1015,
+            Self::ServiceShuttingDown => -1016,
+            Self::UnsupportedOperation => -1020,
+            Self::InvalidTimestamp => -1021,
+            Self::InvalidSignature => -1022,
+
+            Self::IllegalChars => -1100,
+            Self::TooManyParameters => -1101,
+            Self::MandatoryParamEmptyOrMalformed => -1102,
+            Self::UnknownParam => -1103,
+            Self::UnreadParameters => -1104,
+            Self::ParamEmpty => -1105,
+            Self::ParamNotRequired => -1106,
+            Self::ParamOverflow => -1108,
+            Self::BadPrecision => -1111,
+            Self::NoDepth => -1112,
+            Self::TimeInForceNotRequired => -1114,
+            Self::InvalidTimeInForce => -1115,
+            Self::InvalidOrderType => -1116,
+            Self::InvalidSide => -1117,
+            Self::EmptyNewClientOrderId => -1118,
+            Self::EmptyOrigClientOrderId => -1119,
+            Self::BadInterval => -1120,
+            Self::BadSymbol => -1121,
+            Self::InvalidSymbolStatus => -1122,
+            Self::InvalidListenKey => -1125,
+            Self::MoreThanXxHours => -1127,
+            Self::OptionalParamsBadCombo => -1128,
+            Self::InvalidParameter => -1130,
+            Self::BadStrategyType => -1134,
+            Self::InvalidJson => -1135,
+            Self::InvalidTickerType => -1139,
+            Self::InvalidCancelRestrictions => -1145,
+            Self::DuplicateSymbols => -1151,
+            Self::OcoOrderTypeRejected => -1158,
+            Self::OcoIcebergQtyTimeInForce => -1160,
+            Self::BuyOcoLimitMustBeBelow => -1165,
+            Self::SellOcoLimitMustBeAbove => -1166,
+            Self::BothOcoOrdersCannotBeLimit => -1168,
+            Self::InvalidTimeUnit => -1194,
+            Self::BuyOcoStopLossMustBeAbove => -1196,
+            Self::SellOcoStopLossMustBeBelow => -1197,
+            Self::BuyOcoTakeProfitMustBeBelow => -1198,
+            Self::SellOcoTakeProfitMustBeAbove => -1199,
+
+            Self::NewOrderRejected(_) => -2010,
+            Self::CancelRejected(_) => -2011,
+            Self::NoSuchOrder => -2013,
+            Self::BadApiKeyFormat => -2014,
+            Self::RejectedApiKey => -2015,
+            Self::NoTradingWindow => -2016,
+            Self::CancelReplacePartiallyFailed => -2021,
+            Self::CancelReplaceFailed => -2022,
+            Self::OrderArchived => -2026,
+            Self::OrderAmendRejected(_) => -2038,
+
+            Self::Unknown(value) => *value,
+        }
+    }
+
+    // The same request may succeed when sent again later. Disconnected,
+    // unexpected response and timeout leave the execution status unknown,
+    // so orders must be checked before being sent again.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::Disconnected
+                | Self::TooManyRequests
+                | Self::UnexpectedResponse
+                | Self::Timeout
+                | Self::ServerBusy
+                | Self::TooManyOrders
+                | Self::ServiceShuttingDown
+                | Self::InvalidTimestamp
+        )
+    }
+
+    // The request may or may not have been executed
+    pub fn is_execution_unknown(&self) -> bool {
+        matches!(
+            self,
+            Self::Disconnected | Self::UnexpectedResponse | Self::Timeout
+        )
+    }
+
+    pub fn reject_reason(&self) -> Option<&RejectReason> {
+        match self {
+            Self::NewOrderRejected(value) => Some(value),
+            Self::CancelRejected(value) => Some(value),
+            Self::OrderAmendRejected(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+// Messages of -2010 NEW_ORDER_REJECTED, -2011 CANCEL_REJECTED and -2038
+// ORDER_AMEND_REJECTED
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RejectReason {
+    UnknownOrder,
+    DuplicateOrder,
+    MarketClosed,
+    InsufficientBalance,
+    MarketOrdersNotSupported,
+    IcebergOrdersNotSupported,
+    StopLossOrdersNotSupported,
+    StopLossLimitOrdersNotSupported,
+    TakeProfitOrdersNotSupported,
+    TakeProfitLimitOrdersNotSupported,
+    QuoteOrderQtyNotSupported,
+    TrailingStopOrdersNotSupported,
+    OcoOrdersNotSupported,
+    CancelReplaceNotSupported,
+    OrderAmendNotSupported,
+    OrderAmendQuantityIncrease,
+    PriceQtyZeroOrLess,
+    IcebergQtyExceedsQty,
+    ActionDisabled,
+    AccountCannotTrade,
+    UnsupportedOrderCombination,
+    WouldTriggerImmediately,
+    InvalidCancelOrder,
+    WouldImmediatelyMatch,
+    InvalidPriceRelationship,
+    SymbolNotPermitted,
+    SymbolRestricted,
+    CancelRestrictions,
+    RestApiTradingDisabled,
+    InsufficientLiquidity,
+    // The filter name of "Filter failure: PRICE_FILTER" and the like
+    FilterFailure(String),
+    Other,
+}
+
+impl RejectReason {
+    pub fn new(msg: &str) -> Self {
+        if let Some(value) = msg.strip_prefix("Filter failure: ") {
+            return Self::FilterFailure(value.trim().to_string());
+        }
+
+        match msg.trim_end_matches('.') {
+            "Unknown order sent" => Self::UnknownOrder,
+            "Duplicate order sent" => Self::DuplicateOrder,
+            "Market is closed" => Self::MarketClosed,
+            "Account has insufficient balance for requested action" => Self::InsufficientBalance,
+            "Market orders are not supported for this symbol" => Self::MarketOrdersNotSupported,
+            "Iceberg orders are not supported for this symbol" => Self::IcebergOrdersNotSupported,
+            "Stop loss orders are not supported for this symbol" => {
+                Self::StopLossOrdersNotSupported
+            }
+            "Stop loss limit orders are not supported for this symbol" => {
+                Self::StopLossLimitOrdersNotSupported
+            }
+            "Take profit orders are not supported for this symbol" => {
+                Self::TakeProfitOrdersNotSupported
+            }
+            "Take profit limit orders are not supported for this symbol" => {
+                Self::TakeProfitLimitOrdersNotSupported
+            }
+            "Quote order qty market orders are not support for this symbol"
+            | "Quote order qty market orders are not supported for this symbol" => {
+                Self::QuoteOrderQtyNotSupported
+            }
+            "Trailing stop orders are not supported for this symbol" => {
+                Self::TrailingStopOrdersNotSupported
+            }
+            "OCO orders are not supported for this symbol" => Self::OcoOrdersNotSupported,
+            "Order cancel-replace is not supported for this symbol" => {
+                Self::CancelReplaceNotSupported
+            }
+            "Order amend is not supported for this symbol" => Self::OrderAmendNotSupported,
+            "Order amend (quantity increase) is not supported" => Self::OrderAmendQuantityIncrease,
+            "Price * QTY is zero or less" => Self::PriceQtyZeroOrLess,
+            "IcebergQty exceeds QTY" => Self::IcebergQtyExceedsQty,
+            "This action is disabled on this account" => Self::ActionDisabled,
+            "This account may not place or cancel orders" => Self::AccountCannotTrade,
+            "Unsupported order combination" => Self::UnsupportedOrderCombination,
+            "Order would trigger immediately" => Self::WouldTriggerImmediately,
+            "Cancel order is invalid. Check origClOrdId and orderId" => Self::InvalidCancelOrder,
+            "Order would immediately match and take" => Self::WouldImmediatelyMatch,
+            "The relationship of the prices for the orders is not correct" => {
+                Self::InvalidPriceRelationship
+            }
+            "This symbol is not permitted for this account" => Self::SymbolNotPermitted,
+            "This symbol is restricted for this account" => Self::SymbolRestricted,
+            "Order was not canceled due to cancel restrictions" => Self::CancelRestrictions,
+            "Rest API trading is not enabled" => Self::RestApiTradingDisabled,
+            value if value.starts_with("Order book liquidity is less than") => {
+                Self::InsufficientLiquidity
+            }
+            _ => Self::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinanceErrorCode, RejectReason};
+
+    #[test]
+    fn test_binance_error_code() {
+        let code = BinanceErrorCode::new(
+            -1021,
+            "Timestamp for this request is outside of the recvWindow.",
+        );
+        assert_eq!(code, BinanceErrorCode::InvalidTimestamp);
+        assert!(code.is_retryable());
+
+        let code = BinanceErrorCode::new(
+            -2010,
+            "Account has insufficient balance for requested action.",
+        );
+        assert_eq!(
+            code,
+            BinanceErrorCode::NewOrderRejected(RejectReason::InsufficientBalance)
+        );
+        assert_eq!(code.code(), -2010);
+        assert!(!code.is_retryable());
+
+        let code = BinanceErrorCode::new(-2010, "Filter failure: LOT_SIZE");
+        assert_eq!(
+            code.reject_reason(),
+            Some(&RejectReason::FilterFailure("LOT_SIZE".into()))
+        );
+
+        let code = BinanceErrorCode::new(-2011, "Unknown order sent.");
+        assert_eq!(
+            code,
+            BinanceErrorCode::CancelRejected(RejectReason::UnknownOrder)
+        );
+
+        let code = BinanceErrorCode::new(-2010, "Something new.");
+        assert_eq!(code.reject_reason(), Some(&RejectReason::Other));
+
+        let code = BinanceErrorCode::new(-9999, "");
+        assert_eq!(
+            (code.clone(), code.code()),
+            (BinanceErrorCode::Unknown(-9999), -9999)
+        );
+
+        for value in (-1199..=-1000).chain(-2038..=-2010) {
+            assert_eq!(BinanceErrorCode::new(value, "").code(), value);
+        }
+    }
+}
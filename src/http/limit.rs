@@ -0,0 +1,723 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+use super::client::ClientResult;
+use super::error::{BinanceError, ClientError, HttpResponse, RateLimitError};
+
+use crate::market::RateLimit;
+use crate::time::timestamp;
+
+const USED_WEIGHT_HEADER: &str = "x-mbx-used-weight-";
+const ORDER_COUNT_HEADER: &str = "x-mbx-order-count-";
+
+// The limits of `ExchangeInfo::rate_limits` only cover the `/api` endpoints,
+// `/sapi` ones are counted by Binance separately per IP and per UID
+pub(crate) fn is_limited_path(path: &str) -> bool {
+    !path.starts_with("/sapi/")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RateLimitType {
+    #[serde(rename = "REQUEST_WEIGHT")]
+    RequestWeight,
+
+    #[serde(rename = "ORDERS")]
+    Orders,
+
+    #[serde(rename = "RAW_REQUESTS")]
+    RawRequests,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl RateLimitType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::RequestWeight => "REQUEST_WEIGHT",
+            Self::Orders => "ORDERS",
+            Self::RawRequests => "RAW_REQUESTS",
+            Self::Unknown(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RateLimitInterval {
+    #[serde(rename = "SECOND")]
+    Second,
+
+    #[serde(rename = "MINUTE")]
+    Minute,
+
+    #[serde(rename = "HOUR")]
+    Hour,
+
+    #[serde(rename = "DAY")]
+    Day,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl RateLimitInterval {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Second => "SECOND",
+            Self::Minute => "MINUTE",
+            Self::Hour => "HOUR",
+            Self::Day => "DAY",
+            Self::Unknown(v) => v,
+        }
+    }
+
+    pub fn as_duration(&self) -> Option<Duration> {
+        match self {
+            Self::Second => Some(Duration::from_secs(1)),
+            Self::Minute => Some(Duration::from_secs(60)),
+            Self::Hour => Some(Duration::from_secs(3600)),
+            Self::Day => Some(Duration::from_secs(86400)),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    // Unit letter of the response headers, as in `X-MBX-USED-WEIGHT-1M`
+    fn parse_letter(value: &str) -> Option<Self> {
+        match value {
+            "s" | "S" => Some(Self::Second),
+            "m" | "M" => Some(Self::Minute),
+            "h" | "H" => Some(Self::Hour),
+            "d" | "D" => Some(Self::Day),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RateLimitKey {
+    pub rate_limit_type: RateLimitType,
+
+    pub interval: RateLimitInterval,
+
+    pub interval_num: u32,
+}
+
+impl RateLimitKey {
+    pub fn new(
+        rate_limit_type: RateLimitType,
+        interval: RateLimitInterval,
+        interval_num: u32,
+    ) -> Self {
+        Self {
+            rate_limit_type,
+            interval,
+            interval_num,
+        }
+    }
+
+    pub fn window(&self) -> Option<Duration> {
+        Some(self.interval.as_duration()? * self.interval_num.max(1))
+    }
+
+    // Only keys of known types and intervals are tracked
+    fn is_known(&self) -> bool {
+        !matches!(self.rate_limit_type, RateLimitType::Unknown(_)) && self.window().is_some()
+    }
+
+    // `1M` out of `x-mbx-used-weight-1m` and the like
+    fn parse_header(rate_limit_type: RateLimitType, suffix: &str) -> Option<Self> {
+        let (num, letter) = suffix.split_at(suffix.len().checked_sub(1)?);
+        let interval = RateLimitInterval::parse_letter(letter)?;
+
+        Some(Self::new(rate_limit_type, interval, num.parse().ok()?))
+    }
+
+    // Windows start at multiples of their length since UNIX EPOCH
+    fn window_index(&self, now: Duration) -> u128 {
+        now.as_millis() / self.window_millis()
+    }
+
+    fn window_end(&self, now: Duration) -> Duration {
+        let millis = (self.window_index(now) + 1) * self.window_millis();
+
+        Duration::from_millis(millis as u64)
+    }
+
+    fn window_millis(&self) -> u128 {
+        self.window().map_or(1, |v| v.as_millis())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RateLimitUsage {
+    pub key: RateLimitKey,
+
+    pub used: u32,
+
+    pub limit: Option<u32>,
+
+    // Time since UNIX EPOCH at which the current window ends
+    pub resets_at: Duration,
+}
+
+impl RateLimitUsage {
+    pub fn remaining(&self) -> Option<u32> {
+        self.limit.map(|v| v.saturating_sub(self.used))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackoffPolicy {
+    // Requests are sent regardless of a previous 429 or 418
+    #[default]
+    Disabled,
+
+    // Requests fail locally until the `Retry-After` of a 429 has passed or
+    // the ban of a 418 has been lifted
+    Refuse,
+}
+
+#[derive(Debug, Clone)]
+struct Backoff {
+    banned: bool,
+    until: Duration,
+    error: RateLimitError,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct RateLimitEntry {
+    used: u32,
+    limit: Option<u32>,
+    window_index: u128,
+}
+
+// Tracks the usage reported by Binance in the response headers against the
+// limits of `ExchangeInfo::rate_limits`, and optionally waits for the next
+// window before sending a request that would go over one of them
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    throttle: bool,
+    backoff_policy: BackoffPolicy,
+    entries: Mutex<HashMap<RateLimitKey, RateLimitEntry>>,
+    backoff: Mutex<Option<Backoff>>,
+}
+
+impl RateLimiter {
+    pub fn new(throttle: bool, backoff_policy: BackoffPolicy) -> Self {
+        Self {
+            throttle,
+            backoff_policy,
+            entries: Mutex::new(HashMap::new()),
+            backoff: Mutex::new(None),
+        }
+    }
+
+    pub fn is_throttled(&self) -> bool {
+        self.throttle
+    }
+
+    pub fn backoff_policy(&self) -> BackoffPolicy {
+        self.backoff_policy
+    }
+
+    pub fn is_banned(&self) -> bool {
+        self.backoff_at(timestamp()).is_some_and(|v| v.banned)
+    }
+
+    // Time since UNIX EPOCH until which Binance asked not to send requests
+    pub fn backoff_until(&self) -> Option<Duration> {
+        self.backoff_at(timestamp()).map(|v| v.until)
+    }
+
+    pub fn usage(&self) -> Vec<RateLimitUsage> {
+        self.usage_at(timestamp())
+    }
+
+    pub fn get(&self, key: &RateLimitKey) -> Option<RateLimitUsage> {
+        self.usage().into_iter().find(|v| &v.key == key)
+    }
+
+    // Limits of unknown types or intervals are ignored
+    pub fn set_limits(&self, rate_limits: &[RateLimit]) {
+        let mut entries = self.entries.lock().unwrap();
+
+        for rate_limit in rate_limits {
+            let key = RateLimitKey::new(
+                rate_limit.rate_limit_type.clone(),
+                rate_limit.interval.clone(),
+                rate_limit.interval_num.into(),
+            );
+
+            if key.is_known() {
+                entries.entry(key).or_default().limit = Some(rate_limit.limit);
+            }
+        }
+    }
+
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        self.update_at(headers, timestamp())
+    }
+
+    pub(crate) fn check_backoff(&self) -> ClientResult<()> {
+        match self.backoff_policy {
+            BackoffPolicy::Disabled => Ok(()),
+            BackoffPolicy::Refuse => self.check_backoff_at(timestamp()),
+        }
+    }
+
+    // Builds the error of a 429 or 418 response and remembers until when
+    // Binance asked to back off
+    pub(crate) fn rate_limit_error(&self, response: HttpResponse) -> ClientError {
+        self.rate_limit_error_at(response, timestamp())
+    }
+
+    // Counts the request against the known limits, when throttling waits
+    // until every window it would overflow has ended
+    pub(crate) async fn acquire(&self, weight: u32, orders: u32) {
+        loop {
+            let wait = self.reserve_at(weight, orders, timestamp());
+
+            match wait {
+                Some(value) => tokio::time::sleep(value).await,
+                None => return,
+            }
+        }
+    }
+
+    fn backoff_at(&self, now: Duration) -> Option<Backoff> {
+        let backoff = self.backoff.lock().unwrap();
+
+        backoff.as_ref().filter(|v| v.until > now).cloned()
+    }
+
+    fn check_backoff_at(&self, now: Duration) -> ClientResult<()> {
+        let backoff = match self.backoff_at(now) {
+            Some(value) => value,
+            None => return Ok(()),
+        };
+
+        let error = RateLimitError::new(
+            backoff.error.status(),
+            Some(backoff.until - now),
+            backoff.error.banned_until(),
+            backoff.error.binance_error().cloned(),
+            None,
+        );
+
+        match backoff.banned {
+            true => Err(ClientError::Banned(error)),
+            false => Err(ClientError::RateLimited(error)),
+        }
+    }
+
+    fn rate_limit_error_at(&self, response: HttpResponse, now: Duration) -> ClientError {
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get("retry-after")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+
+        let error = serde_json::from_str::<BinanceError>(response.body()).ok();
+        let banned = status == 418;
+        let banned_until = match banned {
+            true => error
+                .as_ref()
+                .and_then(|v| v.banned_until())
+                .or(retry_after.map(|v| now + v)),
+            false => None,
+        };
+
+        let error = RateLimitError::new(status, retry_after, banned_until, error, Some(response));
+
+        if let Some(until) = banned_until.or(retry_after.map(|v| now + v)) {
+            let backoff = Backoff {
+                banned,
+                until,
+                error: error.clone(),
+            };
+
+            *self.backoff.lock().unwrap() = Some(backoff);
+        }
+
+        match banned {
+            true => ClientError::Banned(error),
+            false => ClientError::RateLimited(error),
+        }
+    }
+
+    fn usage_at(&self, now: Duration) -> Vec<RateLimitUsage> {
+        let entries = self.entries.lock().unwrap();
+
+        entries
+            .iter()
+            .map(|(key, entry)| RateLimitUsage {
+                key: key.clone(),
+                used: match entry.window_index == key.window_index(now) {
+                    true => entry.used,
+                    false => 0,
+                },
+                limit: entry.limit,
+                resets_at: key.window_end(now),
+            })
+            .collect()
+    }
+
+    fn update_at(&self, headers: &HeaderMap, now: Duration) {
+        let mut entries = self.entries.lock().unwrap();
+
+        for (name, value) in headers {
+            let name = name.as_str();
+
+            let key = if let Some(suffix) = name.strip_prefix(USED_WEIGHT_HEADER) {
+                RateLimitKey::parse_header(RateLimitType::RequestWeight, suffix)
+            } else if let Some(suffix) = name.strip_prefix(ORDER_COUNT_HEADER) {
+                RateLimitKey::parse_header(RateLimitType::Orders, suffix)
+            } else {
+                None
+            };
+
+            let used = value.to_str().ok().and_then(|v| v.parse::<u32>().ok());
+
+            if let (Some(key), Some(used)) = (key, used) {
+                let window_index = key.window_index(now);
+                let entry = entries.entry(key).or_default();
+                entry.used = used;
+                entry.window_index = window_index;
+            }
+        }
+    }
+
+    fn reserve_at(&self, weight: u32, orders: u32, now: Duration) -> Option<Duration> {
+        let mut entries = self.entries.lock().unwrap();
+
+        let cost = |key: &RateLimitKey| match key.rate_limit_type {
+            RateLimitType::RequestWeight => weight,
+            RateLimitType::Orders => orders,
+            RateLimitType::RawRequests => 1,
+            RateLimitType::Unknown(_) => 0,
+        };
+
+        if self.throttle {
+            let wait = entries
+                .iter()
+                .filter_map(|(key, entry)| {
+                    let limit = entry.limit?;
+                    let used = match entry.window_index == key.window_index(now) {
+                        true => entry.used,
+                        false => 0,
+                    };
+
+                    // A request costing more than the whole limit never fits
+                    match cost(key) <= limit && used + cost(key) > limit {
+                        true => Some(key.window_end(now) - now),
+                        false => None,
+                    }
+                })
+                .max();
+
+            if wait.is_some() {
+                return wait;
+            }
+        }
+
+        for (key, entry) in entries.iter_mut() {
+            let index = key.window_index(now);
+
+            if entry.window_index != index {
+                entry.window_index = index;
+                entry.used = 0;
+            }
+
+            entry.used = entry.used.saturating_add(cost(key));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::time::Duration;
+
+    use reqwest::header::HeaderMap;
+
+    use super::{BackoffPolicy, RateLimitInterval, RateLimitKey, RateLimitType, RateLimiter};
+
+    use crate::http::client::{
+        tests::{mock_server, mock_server_with_headers},
+        Client,
+    };
+    use crate::http::error::{BinanceError, ClientError, HttpResponse};
+    use crate::market::RateLimit;
+    use crate::time::timestamp;
+
+    fn rate_limit(
+        rate_limit_type: &str,
+        interval: &str,
+        interval_num: u8,
+        limit: u32,
+    ) -> RateLimit {
+        let json_data = format!(
+            r#"{{"rateLimitType":"{}","interval":"{}","intervalNum":{},"limit":{}}}"#,
+            rate_limit_type, interval, interval_num, limit
+        );
+
+        serde_json::from_str(&json_data).unwrap()
+    }
+
+    #[test]
+    fn test_rate_limiter_headers() {
+        let limiter = RateLimiter::default();
+        limiter.set_limits(&[
+            rate_limit("REQUEST_WEIGHT", "MINUTE", 1, 6000),
+            rate_limit("ORDERS", "SECOND", 10, 100),
+            rate_limit("UNKNOWN", "MINUTE", 1, 1),
+            rate_limit("ORDERS", "WEEK", 1, 1),
+        ]);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-MBX-USED-WEIGHT-1M", "42".parse().unwrap());
+        headers.insert("X-MBX-ORDER-COUNT-10S", "3".parse().unwrap());
+        headers.insert("X-MBX-ORDER-COUNT-1D", "7".parse().unwrap());
+        headers.insert("X-MBX-USED-WEIGHT", "42".parse().unwrap());
+
+        let now = Duration::from_millis(1_700_000_005_000);
+        limiter.update_at(&headers, now);
+
+        let usage = limiter.usage_at(now);
+        assert_eq!(usage.len(), 3);
+
+        let weight = RateLimitKey::new(RateLimitType::RequestWeight, RateLimitInterval::Minute, 1);
+        let weight = usage.iter().find(|v| v.key == weight).unwrap();
+        assert_eq!((weight.used, weight.remaining()), (42, Some(5958)));
+        assert_eq!(weight.resets_at, Duration::from_millis(1_700_000_040_000));
+
+        let orders = RateLimitKey::new(RateLimitType::Orders, RateLimitInterval::Day, 1);
+        let orders = usage.iter().find(|v| v.key == orders).unwrap();
+        assert_eq!((orders.used, orders.limit), (7, None));
+
+        let usage = limiter.usage_at(now + Duration::from_secs(60));
+        assert!(usage
+            .iter()
+            .all(|v| v.used == 0 || v.key.interval == RateLimitInterval::Day));
+    }
+
+    #[test]
+    fn test_rate_limiter_throttle() {
+        let limiter = RateLimiter::new(true, BackoffPolicy::Disabled);
+        limiter.set_limits(&[
+            rate_limit("REQUEST_WEIGHT", "MINUTE", 1, 100),
+            rate_limit("ORDERS", "SECOND", 10, 2),
+        ]);
+
+        let now = Duration::from_millis(1_700_000_005_000);
+
+        assert_eq!(limiter.reserve_at(90, 1, now), None);
+        assert_eq!(
+            limiter.reserve_at(20, 0, now),
+            Some(Duration::from_secs(35))
+        );
+        assert_eq!(limiter.reserve_at(5, 1, now), None);
+        assert_eq!(limiter.reserve_at(1, 1, now), Some(Duration::from_secs(5)));
+        assert_eq!(limiter.reserve_at(500, 0, now), None);
+
+        let now = now + Duration::from_secs(35);
+        assert_eq!(limiter.reserve_at(20, 1, now), None);
+    }
+
+    #[tokio::test]
+    async fn test_client_tracks_used_weight() {
+        let headers = vec![("X-MBX-USED-WEIGHT-1M", "21")];
+        let (base_url, requests) =
+            mock_server_with_headers(vec![(200, headers, "{}".into())]).await;
+
+        let client = Client::builder().set_base_url(base_url).build().unwrap();
+        client.server_ping().await.unwrap();
+        requests.await.unwrap();
+
+        let key = RateLimitKey::new(RateLimitType::RequestWeight, RateLimitInterval::Minute, 1);
+        let usage = client.rate_limiter().get(&key).unwrap();
+        assert_eq!(usage.used, 21);
+    }
+
+    #[tokio::test]
+    async fn test_throttled_request_is_stamped_after_wait() {
+        let (base_url, requests) = mock_server(vec![(200, "[]".into()), (200, "[]".into())]).await;
+
+        let client = Client::builder()
+            .set_base_url(base_url)
+            .set_api_key("key".into())
+            .set_secret_key("secret".into())
+            .set_throttle(true)
+            .build()
+            .unwrap();
+        client
+            .rate_limiter()
+            .set_limits(&[rate_limit("REQUEST_WEIGHT", "SECOND", 1, 100)]);
+
+        // Both reservations have to fall in the same one second window
+        let millis = timestamp().as_millis() % 1000;
+        if millis > 500 {
+            tokio::time::sleep(Duration::from_millis(1010 - millis as u64)).await;
+        }
+
+        client.spot_open_orders(None, None).await.unwrap();
+
+        let key = RateLimitKey::new(RateLimitType::RequestWeight, RateLimitInterval::Second, 1);
+        let usage = client.rate_limiter().get(&key).unwrap();
+        assert_eq!(usage.used, 80);
+
+        client.spot_open_orders(None, None).await.unwrap();
+
+        let requests = requests.await.unwrap();
+        let sent_at: u128 = requests[1]
+            .split("timestamp=")
+            .nth(1)
+            .and_then(|v| v.split('&').next())
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(sent_at >= usage.resets_at.as_millis());
+    }
+
+    #[tokio::test]
+    async fn test_client_reserves_endpoint_weight() {
+        let order_book = r#"{"lastUpdateId":1027024,"bids":[],"asks":[]}"#;
+        let (base_url, _) = mock_server(vec![(200, order_book.into()), (200, "[]".into())]).await;
+
+        let client = Client::builder()
+            .set_base_url(base_url)
+            .set_api_key("key".into())
+            .set_throttle(true)
+            .build()
+            .unwrap();
+        client
+            .rate_limiter()
+            .set_limits(&[rate_limit("REQUEST_WEIGHT", "DAY", 1, 100_000)]);
+
+        let key = RateLimitKey::new(RateLimitType::RequestWeight, RateLimitInterval::Day, 1);
+        let symbol = "BTCUSDT".parse().unwrap();
+
+        client.order_book(&symbol, Some(5000)).await.unwrap();
+        let used = client.rate_limiter().get(&key).unwrap().used;
+        assert_eq!(used, 250);
+
+        client.historical_trades(&symbol, None, None).await.unwrap();
+        let used = client.rate_limiter().get(&key).unwrap().used;
+        assert_eq!(used, 275);
+    }
+
+    #[tokio::test]
+    async fn test_client_skips_sapi_weight() {
+        let (base_url, _) = mock_server(vec![(200, "[]".into())]).await;
+
+        let client = Client::builder()
+            .set_base_url(base_url)
+            .set_api_key("key".into())
+            .set_secret_key("secret".into())
+            .set_throttle(true)
+            .build()
+            .unwrap();
+        client
+            .rate_limiter()
+            .set_limits(&[rate_limit("REQUEST_WEIGHT", "DAY", 1, 100_000)]);
+
+        client
+            .trade_fee(&"BTCUSDT".parse().unwrap(), None)
+            .await
+            .unwrap();
+
+        let key = RateLimitKey::new(RateLimitType::RequestWeight, RateLimitInterval::Day, 1);
+        assert_eq!(client.rate_limiter().get(&key).unwrap().used, 0);
+    }
+
+    #[test]
+    fn test_rate_limiter_backoff() {
+        let limiter = RateLimiter::new(false, BackoffPolicy::Refuse);
+        let now = Duration::from_millis(1_659_146_300_000);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("Retry-After", "30".parse().unwrap());
+
+        let body = r#"{"code":-1003,"msg":"Too many requests; current limit of IP(127.0.0.1) is 6000 requests per minute."}"#;
+        let response = HttpResponse::new(429, "/api/v3/order".into(), headers, body.into());
+        let error = limiter.rate_limit_error_at(response, now);
+        assert!(error.source().unwrap().is::<BinanceError>());
+        match error {
+            ClientError::RateLimited(e) => {
+                assert_eq!(e.status(), 429);
+                assert_eq!(e.retry_after(), Some(Duration::from_secs(30)));
+                assert_eq!(e.banned_until(), None);
+                assert!(e.binance_error().is_some());
+                assert_eq!(e.response().unwrap().path(), "/api/v3/order");
+            }
+            _ => panic!("{:?}", error),
+        }
+
+        match limiter.check_backoff_at(now + Duration::from_secs(10)) {
+            Err(ClientError::RateLimited(e)) => {
+                assert_eq!(e.retry_after(), Some(Duration::from_secs(20)))
+            }
+            result => panic!("{:?}", result),
+        }
+        assert!(limiter
+            .check_backoff_at(now + Duration::from_secs(30))
+            .is_ok());
+
+        let body = r#"{"code":-1003,"msg":"Way too many requests; IP(127.0.0.1) banned until 1659146373035. Please use the websocket for live updates to avoid bans."}"#;
+        let response =
+            HttpResponse::new(418, "/api/v3/order".into(), HeaderMap::new(), body.into());
+        let error = limiter.rate_limit_error_at(response, now);
+        assert!(
+            matches!(&error, ClientError::Banned(e) if e.banned_until() == Some(Duration::from_millis(1_659_146_373_035)))
+        );
+        assert!(error.source().unwrap().is::<BinanceError>());
+
+        match limiter.check_backoff_at(now + Duration::from_secs(73)) {
+            Err(ClientError::Banned(e)) => {
+                assert_eq!(e.retry_after(), Some(Duration::from_millis(35)));
+                assert_eq!(e.status(), 418);
+            }
+            result => panic!("{:?}", result),
+        }
+        assert!(limiter
+            .check_backoff_at(now + Duration::from_secs(74))
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_client_refuses_while_banned() {
+        let headers = vec![("Retry-After", "120")];
+        let body = r#"{"code":-1003,"msg":"Way too many requests."}"#;
+        let (base_url, requests) =
+            mock_server_with_headers(vec![(418, headers, body.into())]).await;
+
+        let client = Client::builder()
+            .set_base_url(base_url)
+            .set_backoff_policy(BackoffPolicy::Refuse)
+            .build()
+            .unwrap();
+
+        match client.server_ping().await {
+            Err(ClientError::Banned(e)) => {
+                assert_eq!(e.retry_after(), Some(Duration::from_secs(120)));
+                assert!(e.banned_until().is_some());
+            }
+            result => panic!("{:?}", result),
+        }
+
+        assert!(client.rate_limiter().is_banned());
+        assert!(matches!(
+            client.server_ping().await,
+            Err(ClientError::Banned(_))
+        ));
+        assert_eq!(requests.await.unwrap().len(), 1);
+    }
+}
@@ -1,4 +1,8 @@
 pub mod client;
 pub mod error;
+pub mod error_code;
+pub mod limit;
+pub mod retry;
+pub mod sign;
 
 mod request;
@@ -1,52 +1,78 @@
-use ring::hmac;
+use std::time::Duration;
+
 use serde::Deserialize;
+use serde_json::Value as JsonValue;
 use url::Url;
 
 use super::client::{Client, ClientResult};
-use super::error::{BinanceError, ClientError};
+use super::error::{BinanceError, ClientError, HttpResponse};
+use super::error_code::BinanceErrorCode;
+use super::limit::is_limited_path;
+use super::retry::Failure;
+use super::sign::{Signer, SigningKey};
+
+use crate::time::{recv_window_param, with_time_unit};
+
+// Lookups of an order whose placement status is unknown before it is sent
+// again with the same `newClientOrderId`
+const ORDER_LOOKUPS: u32 = 2;
 
 impl Client {
     pub fn base_url(&self) -> ClientResult<Url> {
         Ok(Url::parse(&self.base_url)?)
     }
 
-    pub(crate) fn build_request_get(&self, url: Url) -> RequestBuilder {
-        let inner = self.inner.get(url);
+    pub(crate) fn build_request_get(&self, url: Url) -> RequestBuilder<'_> {
+        RequestBuilder::new(self, self.inner.get(url), RetryMode::Safe)
+    }
+
+    pub(crate) fn build_request_post(&self, url: Url) -> RequestBuilder<'_> {
+        RequestBuilder::new(self, self.inner.post(url), RetryMode::Unsafe)
+    }
+
+    pub(crate) fn build_request_delete(&self, url: Url) -> RequestBuilder<'_> {
+        RequestBuilder::new(self, self.inner.delete(url), RetryMode::Unsafe)
+    }
+
+    pub(crate) fn build_request_put(&self, url: Url) -> RequestBuilder<'_> {
+        RequestBuilder::new(self, self.inner.put(url), RetryMode::Unsafe)
+    }
 
-        RequestBuilder { inner }
+    pub(crate) fn build_api_key_request_get(&self, url: Url) -> ClientResult<RequestBuilder<'_>> {
+        Ok(self
+            .build_request_get(url)
+            .with_api_key(self.secret.api_key()?))
     }
 
-    pub(crate) fn build_request_post(&self, url: Url) -> RequestBuilder {
-        let inner = self.inner.post(url);
+    // The query is signed when the request is sent, these only check that a
+    // signer has been configured
+    pub(crate) fn build_sign_request_get(&self, url: Url) -> ClientResult<RequestBuilder<'_>> {
+        self.secret.signer()?;
+        Ok(self.build_request_get(url).with_signature())
+    }
 
-        RequestBuilder { inner }
+    pub(crate) fn build_sign_request_post(&self, url: Url) -> ClientResult<RequestBuilder<'_>> {
+        self.secret.signer()?;
+        Ok(self.build_request_post(url).with_signature())
     }
 
-    pub(crate) fn build_sign_request_get(&self, url: Url) -> ClientResult<RequestBuilder> {
-        let url = self.sign_url_query(url)?;
-        Ok(self.build_request_get(url))
+    pub(crate) fn build_sign_request_delete(&self, url: Url) -> ClientResult<RequestBuilder<'_>> {
+        self.secret.signer()?;
+        Ok(self.build_request_delete(url).with_signature())
     }
 
-    pub(crate) fn build_sign_request_post(&self, url: Url) -> ClientResult<RequestBuilder> {
-        let url = self.sign_url_query(url)?;
-        Ok(self.build_request_post(url))
+    pub(crate) fn build_sign_request_put(&self, url: Url) -> ClientResult<RequestBuilder<'_>> {
+        self.secret.signer()?;
+        Ok(self.build_request_put(url).with_signature())
     }
 
-    fn sign_url_query(&self, mut url: Url) -> ClientResult<Url> {
+    async fn sign_url_query(&self, mut url: Url) -> ClientResult<Url> {
         let query = match url.query() {
             Some(v) => v.to_string(),
-            None => return Err(ClientError::Request("Empty Query".to_string())),
+            None => return Err(ClientError::Internal("Empty Query".to_string())),
         };
 
-        let secret = self.secret.secret_key()?;
-        let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
-        let tag = hmac::sign(&key, query.as_bytes());
-
-        let value: String = tag
-            .as_ref()
-            .iter()
-            .map(|byte| format!("{:02x}", byte))
-            .collect();
+        let value = self.secret.signer()?.sign(&query).await?;
 
         {
             url.query_pairs_mut().append_pair("signature", &value);
@@ -54,13 +80,83 @@ impl Client {
 
         Ok(url)
     }
+
+    // The order as a placement response, `None` when Binance does not know
+    // of it. The query leaves out the fills, which are reported empty.
+    async fn placed_order(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> ClientResult<Option<HttpResponse>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/order");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+            query_pairs.append_pair("origClientOrderId", client_order_id);
+        }
+
+        // Boxed since the query goes through `execute` itself
+        let request = self
+            .build_sign_request_get(url)?
+            .with_weight(4)
+            .with_api_key(self.secret.api_key()?);
+        let response = Box::pin(request.execute()).await?;
+
+        if !response.is_success() {
+            let error = self.response_error(response);
+
+            return match error.binance_error().map(|v| v.error_code()) {
+                Some(BinanceErrorCode::NoSuchOrder) => Ok(None),
+                _ => Err(error),
+            };
+        }
+
+        let path = response.path().to_string();
+        let headers = response.headers().clone();
+        let time_unit = response.time_unit();
+        let mut order: JsonValue = response.decode()?;
+
+        if let Some(order) = order.as_object_mut() {
+            let time = order.get("time").cloned().unwrap_or_default();
+            order.entry("transactTime").or_insert(time);
+            order.entry("fills").or_insert(JsonValue::Array(Vec::new()));
+        }
+
+        let body = serde_json::to_string(&order)?;
+
+        let response = HttpResponse::new(200, path, headers, body).with_time_unit(time_unit);
+
+        Ok(Some(response))
+    }
+
+    fn response_error(&self, response: HttpResponse) -> ClientError {
+        match serde_json::from_str::<BinanceError>(response.body()) {
+            Ok(v) => self.binance_error(v, response),
+            Err(_) => ClientError::Http(Box::new(response)),
+        }
+    }
+
+    // A -1021 means the timestamp fell outside of the recvWindow, so the
+    // clock offset is measured again before the next signed request
+    fn binance_error(&self, error: BinanceError, response: HttpResponse) -> ClientError {
+        if error.error_code() == BinanceErrorCode::InvalidTimestamp {
+            if let Some(clock_sync) = &self.clock_sync {
+                clock_sync.invalidate();
+            }
+        }
+
+        ClientError::Binance(error, Box::new(response))
+    }
 }
 
 #[rustfmt::skip]
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub(crate) struct Secret {
-    api_key:     Option<String>,
-    secret_key:  Option<String>
+    api_key:  Option<String>,
+    signer:   Option<Box<dyn Signer>>
 }
 
 impl Secret {
@@ -71,9 +167,9 @@ impl Secret {
         }
     }
 
-    pub(crate) fn secret_key(&self) -> ClientResult<&String> {
-        match &self.secret_key {
-            Some(value) => Ok(value),
+    pub(crate) fn signer(&self) -> ClientResult<&dyn Signer> {
+        match &self.signer {
+            Some(value) => Ok(value.as_ref()),
             None => Err(ClientError::Authorization("SECRET KEY".into())),
         }
     }
@@ -83,40 +179,331 @@ impl Secret {
     }
 
     pub fn update_secret_key(&mut self, value: String) {
-        self.secret_key = Some(value)
+        self.update_signer(SigningKey::hmac(&value))
+    }
+
+    pub fn update_signer<S>(&mut self, value: S)
+    where
+        S: Signer + 'static,
+    {
+        self.signer = Some(Box::new(value))
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct RequestBuilder {
-    inner: reqwest::RequestBuilder,
+// The code and message are parsed again as `BinanceError` when there is no
+// `data` to return
+#[derive(Debug, Deserialize)]
+struct BinanceErrorData<T> {
+    data: Option<T>,
 }
 
-impl From<reqwest::RequestBuilder> for RequestBuilder {
-    fn from(value: reqwest::RequestBuilder) -> Self {
-        Self { inner: value }
+// Whether a request may be sent again when its outcome is unknown
+enum RetryMode {
+    // Queries without side effects
+    Safe,
+
+    // Anything else changing state, only resent when known not executed
+    Unsafe,
+
+    // New orders, resent with the same `newClientOrderId` once a query of
+    // the order status shows that none was placed
+    Order {
+        symbol: String,
+        client_order_id: String,
+    },
+}
+
+// The body is read right away so that failed attempts can be inspected
+impl HttpResponse {
+    fn failure(&self) -> Option<Failure> {
+        if self.is_success() {
+            return None;
+        }
+
+        let code = serde_json::from_str::<BinanceError>(self.body())
+            .ok()
+            .map(|v| v.error_code());
+
+        Failure::of_response(self.status(), code)
+    }
+
+    fn decode<T>(self) -> ClientResult<T>
+    where
+        for<'de> T: Deserialize<'de>,
+    {
+        match with_time_unit(self.time_unit(), || serde_json::from_str::<T>(self.body())) {
+            Ok(v) => Ok(v),
+            Err(e) => Err(ClientError::Decode(e, Box::new(self))),
+        }
     }
 }
 
-impl RequestBuilder {
+pub(crate) struct RequestBuilder<'a> {
+    client: &'a Client,
+    inner: reqwest::RequestBuilder,
+    signed: bool,
+    recv_window: Option<Duration>,
+    weight: u32,
+    orders: u32,
+    retry_mode: RetryMode,
+}
+
+impl<'a> RequestBuilder<'a> {
+    fn new(client: &'a Client, inner: reqwest::RequestBuilder, retry_mode: RetryMode) -> Self {
+        Self {
+            client,
+            inner,
+            signed: false,
+            recv_window: None,
+            weight: 1,
+            orders: 0,
+            retry_mode,
+        }
+    }
+
+    async fn execute(self) -> ClientResult<HttpResponse> {
+        let policy = match &self.client.retry_policy {
+            Some(value) => value,
+            None => return self.execute_once().await.map_err(|(e, _)| e),
+        };
+
+        policy.deposit();
+
+        let mut attempt = 0;
+
+        loop {
+            let (result, failure) = match self.execute_once().await {
+                Ok(response) => {
+                    let failure = response.failure();
+                    (Ok(response), failure)
+                }
+                Err((error, failure)) => (Err(error), Some(failure)),
+            };
+
+            let failure = match failure {
+                Some(value) => value,
+                None => return result,
+            };
+
+            let retry = matches!(
+                (failure, &self.retry_mode),
+                (Failure::NotExecuted, _)
+                    | (Failure::Unknown, RetryMode::Safe | RetryMode::Order { .. })
+            );
+
+            // A 429 asks to wait for `Retry-After`, which may be longer than
+            // the policy is willing to
+            let delay = match &result {
+                Err(ClientError::RateLimited(e)) => e.retry_after().unwrap_or_default(),
+                _ => Duration::ZERO,
+            };
+
+            if !retry
+                || attempt >= policy.max_retries()
+                || delay > policy.max_delay()
+                || !policy.withdraw()
+            {
+                return result;
+            }
+
+            tokio::time::sleep(policy.delay(attempt).max(delay)).await;
+            attempt += 1;
+
+            if let (
+                Failure::Unknown,
+                RetryMode::Order {
+                    symbol,
+                    client_order_id,
+                },
+            ) = (failure, &self.retry_mode)
+            {
+                // The matching engine may still be processing the original
+                // order when it is first looked up, so it is only sent again
+                // once a second lookup after another delay misses it too
+                for lookup in 0..ORDER_LOOKUPS {
+                    if lookup > 0 {
+                        tokio::time::sleep(policy.delay(attempt)).await;
+                    }
+
+                    match self.client.placed_order(symbol, client_order_id).await {
+                        Ok(Some(response)) => return Ok(response),
+                        Ok(None) => {}
+                        Err(_) => return result,
+                    }
+                }
+            }
+        }
+    }
+
+    async fn execute_once(&self) -> Result<HttpResponse, (ClientError, Failure)> {
+        let request = match self.prepare().await {
+            Ok(value) => value,
+            Err(e) => {
+                let failure = Failure::of_error(&e);
+                return Err((e, failure));
+            }
+        };
+
+        let path = request.url().path().to_string();
+
+        let response = match self.client.inner.execute(request).await {
+            Ok(value) => value,
+            Err(e) => {
+                let failure = Failure::of_transport(&e);
+                return Err((e.into(), failure));
+            }
+        };
+
+        if is_limited_path(&path) {
+            self.client.rate_limiter.update(response.headers());
+        }
+
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+
+        let body = match response.text().await {
+            Ok(value) => value,
+            Err(e) => {
+                let failure = Failure::of_transport(&e);
+                return Err((e.into(), failure));
+            }
+        };
+
+        let response =
+            HttpResponse::new(status, path, headers, body).with_time_unit(self.client.time_unit);
+
+        if status == 429 || status == 418 {
+            let error = self.client.rate_limiter.rate_limit_error(response);
+            let failure = Failure::of_error(&error);
+
+            return Err((error, failure));
+        }
+
+        Ok(response)
+    }
+
+    // Signed again on every attempt, as the timestamp has to be fresh
+    async fn prepare(&self) -> ClientResult<reqwest::Request> {
+        self.client.rate_limiter.check_backoff()?;
+
+        let inner = match self.inner.try_clone() {
+            Some(value) => value,
+            None => return Err(ClientError::Internal("Stream Body".into())),
+        };
+
+        let mut request = inner.build()?;
+
+        let recv_window = match self.recv_window.or(self.client.recv_window) {
+            Some(value) if self.signed => Some(recv_window_param(value)?),
+            _ => None,
+        };
+
+        // Throttling may wait for the next window, so the request is only
+        // stamped and signed once it is allowed to go out
+        if is_limited_path(request.url().path()) {
+            self.client
+                .rate_limiter
+                .acquire(self.weight, self.orders)
+                .await;
+        }
+
+        if self.signed {
+            let timestamp = self.client.timestamp().await?;
+
+            let mut url = request.url().clone();
+            {
+                let mut query_pairs = url.query_pairs_mut();
+
+                if let Some(value) = recv_window {
+                    query_pairs.append_pair("recvWindow", &value);
+                }
+
+                query_pairs.append_pair("timestamp", &timestamp.as_millis().to_string());
+            }
+
+            *request.url_mut() = self.client.sign_url_query(url).await?;
+        }
+
+        Ok(request)
+    }
+
     pub(crate) async fn send<T>(self) -> ClientResult<T>
     where
-        for<'a> T: Deserialize<'a>,
+        for<'de> T: Deserialize<'de>,
     {
-        let response = self.inner.send().await?;
+        let client = self.client;
+        let response = self.execute().await?;
 
-        if response.status().is_success() {
-            return Ok(response.json::<T>().await?);
+        if response.is_success() {
+            return response.decode();
         }
 
-        match response.json::<BinanceError>().await {
-            Ok(v) => Err(ClientError::Binance(v)),
-            Err(e) => Err(ClientError::Request(e.to_string())),
+        Err(client.response_error(response))
+    }
+
+    // Some endpoints report failures with a `data` payload next to the error
+    // code, which is returned in place of the error when present
+    pub(crate) async fn send_or_error_data<T>(self) -> ClientResult<T>
+    where
+        for<'de> T: Deserialize<'de>,
+    {
+        let client = self.client;
+        let response = self.execute().await?;
+
+        if response.is_success() {
+            return response.decode();
         }
+
+        let data = with_time_unit(response.time_unit(), || {
+            serde_json::from_str::<BinanceErrorData<T>>(response.body())
+        });
+
+        match data {
+            Ok(BinanceErrorData { data: Some(v), .. }) => Ok(v),
+            _ => Err(client.response_error(response)),
+        }
+    }
+
+    pub(crate) fn with_api_key(mut self, value: &String) -> Self {
+        self.inner = self.inner.header("X-MBX-APIKEY", value);
+
+        self
+    }
+
+    pub(crate) fn with_recv_window(mut self, value: Option<Duration>) -> Self {
+        self.recv_window = value;
+
+        self
+    }
+
+    // Request weight of the endpoint, 1 when not set
+    pub(crate) fn with_weight(mut self, value: u32) -> Self {
+        self.weight = value;
+
+        self
+    }
+
+    // Number of orders placed, counted against the unfilled order count
+    pub(crate) fn with_order_count(mut self, value: u32) -> Self {
+        self.orders = value;
+
+        self
     }
 
-    pub(crate) fn with_api_key(self, value: &String) -> Self {
-        self.inner.header("X-MBX-APIKEY", value).into()
+    // Allows to resend a new order after checking it was not placed
+    pub(crate) fn with_new_order(mut self, symbol: &str, client_order_id: &str) -> Self {
+        self.retry_mode = RetryMode::Order {
+            symbol: symbol.to_string(),
+            client_order_id: client_order_id.to_string(),
+        };
+
+        self
+    }
+
+    fn with_signature(mut self) -> Self {
+        self.signed = true;
+
+        self
     }
 
     // pub(crate) fn with_json_body<T>(self, value: &T) -> Self
@@ -126,3 +513,228 @@ impl RequestBuilder {
     //     self.inner.json(value).into()
     // }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::time::Duration;
+
+    use url::Url;
+
+    use crate::http::client::{tests::mock_server, Client};
+    use crate::http::error::{BinanceError, ClientError};
+    use crate::http::error_code::BinanceErrorCode;
+    use crate::http::retry::RetryPolicy;
+    use crate::http::sign::{tests::ED25519_PEM, SignFuture, Signer, SigningKey};
+    use crate::spot::{NewOrder, OrderResponse, OrderSide, OrderType};
+
+    struct ExternalSigner;
+
+    impl Signer for ExternalSigner {
+        fn sign<'a>(&'a self, payload: &'a str) -> SignFuture<'a> {
+            Box::pin(async move {
+                tokio::task::yield_now().await;
+
+                Ok(format!("external+{}", payload.len()))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_url_query_encoding() {
+        let client = Client::builder()
+            .set_signing_key(SigningKey::ed25519_pem(ED25519_PEM).unwrap())
+            .build()
+            .unwrap();
+
+        let url = Url::parse("https://api.binance.com/api/v3/order?symbol=BTCUSDT&side=SELL&type=LIMIT&timeInForce=GTC&quantity=1&price=0.2&timestamp=1668481559918").unwrap();
+        let url = client.sign_url_query(url).await.unwrap();
+
+        assert!(url.query().unwrap().ends_with(
+            "&signature=RfKhLf0xMu1kz9sTNnYmr4oBv5Xpu%2B69wxMwx6auD5f2aSw3Zmqw1fvc%2F2irn6LvPqXEUiuDEFn8YZEaWIKiBw%3D%3D"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sign_url_query_with_signer() {
+        let client = Client::builder()
+            .set_signer(ExternalSigner)
+            .build()
+            .unwrap();
+
+        let url = Url::parse("https://api.binance.com/api/v3/account?timestamp=1").unwrap();
+        let url = client.sign_url_query(url).await.unwrap();
+
+        assert_eq!(url.query(), Some("timestamp=1&signature=external%2B11"));
+    }
+
+    #[tokio::test]
+    async fn test_sign_url_query_without_signer() {
+        let client = Client::builder().build().unwrap();
+
+        let url = Url::parse("https://api.binance.com/api/v3/account?timestamp=1").unwrap();
+        assert!(client.sign_url_query(url.clone()).await.is_err());
+        assert!(client.build_sign_request_get(url).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_signed_request_recv_window() {
+        let (base_url, requests) = mock_server(vec![(200, "[]".into()), (200, "[]".into())]).await;
+
+        let client = Client::builder()
+            .set_base_url(base_url)
+            .set_api_key("key".into())
+            .set_secret_key("secret".into())
+            .set_recv_window(Duration::from_secs(10))
+            .build()
+            .unwrap();
+
+        client.spot_open_orders(None, None).await.unwrap();
+        client
+            .spot_open_orders(None, Some(Duration::from_micros(6000346)))
+            .await
+            .unwrap();
+
+        let result = client
+            .spot_open_orders(None, Some(Duration::from_secs(61)))
+            .await;
+        assert!(matches!(result, Err(ClientError::InvalidParameter(_))));
+
+        let requests = requests.await.unwrap();
+        assert!(requests[0].contains("?recvWindow=10000&timestamp="));
+        assert!(requests[1].contains("?recvWindow=6000.346&timestamp="));
+
+        let result = Client::builder()
+            .set_recv_window(Duration::from_secs(120))
+            .build();
+        assert!(result.is_err());
+    }
+
+    fn client_with_retries(base_url: String) -> Client {
+        let policy = RetryPolicy::new()
+            .set_base_delay(Duration::from_millis(1))
+            .set_max_delay(Duration::from_millis(5));
+
+        Client::builder()
+            .set_base_url(base_url)
+            .set_api_key("key".into())
+            .set_secret_key("secret".into())
+            .set_retry_policy(policy)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_retry_safe_request() {
+        let (base_url, requests) = mock_server(vec![
+            (500, "".into()),
+            (400, r#"{"code":-1001,"msg":"Internal error."}"#.into()),
+            (200, "{}".into()),
+        ])
+        .await;
+
+        let client = client_with_retries(base_url);
+        client.server_ping().await.unwrap();
+
+        assert_eq!(requests.await.unwrap().len(), 3);
+        assert_eq!(client.retry_policy().unwrap().budget(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_retry_unsafe_request() {
+        let (base_url, requests) = mock_server(vec![
+            (503, r#"{"code":-1007,"msg":"Timeout waiting for response from backend server. Send status unknown; execution status unknown."}"#.into()),
+        ])
+        .await;
+
+        let client = client_with_retries(base_url);
+        let result = client
+            .spot_cancel_order(&"BTCUSDT".parse().unwrap(), 1, None, None, None)
+            .await;
+
+        assert!(matches!(result, Err(ClientError::Binance(..))));
+        assert_eq!(requests.await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_new_order() {
+        let order = r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"my-order","price":"0.00000000","origQty":"0.00100000","executedQty":"0.00100000","cummulativeQuoteQty":"60.00000000","status":"FILLED","timeInForce":"GTC","type":"MARKET","side":"BUY","stopPrice":"0.00000000","icebergQty":"0.00000000","time":1507725176595,"updateTime":1507725176595,"isWorking":true,"workingTime":1507725176595,"origQuoteOrderQty":"0.00000000","selfTradePreventionMode":"NONE"}"#;
+
+        let (base_url, requests) = mock_server(vec![
+            (503, r#"{"code":-1007,"msg":"Timeout waiting for response from backend server. Send status unknown; execution status unknown."}"#.into()),
+            (400, r#"{"code":-2013,"msg":"Order does not exist."}"#.into()),
+            (400, r#"{"code":-2013,"msg":"Order does not exist."}"#.into()),
+            (503, "".into()),
+            (200, order.into()),
+        ])
+        .await;
+
+        let client = client_with_retries(base_url);
+        let order = NewOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderSide::Buy,
+            OrderType::Market,
+        )
+        .set_quantity("0.001".parse().unwrap())
+        .set_new_client_order_id("my-order".into());
+
+        let response = client.spot_order(&order, None).await.unwrap();
+        match response {
+            OrderResponse::Full(v) => {
+                assert_eq!(v.order_id, 28);
+                assert_eq!(v.transact_time.as_millis(), 1507725176595);
+                assert!(v.fills.is_empty());
+            }
+            _ => panic!("{:?}", response),
+        }
+
+        let requests = requests.await.unwrap();
+        assert!(requests[0].starts_with("POST /api/v3/order?"));
+        assert!(
+            requests[1].starts_with("GET /api/v3/order?symbol=BTCUSDT&origClientOrderId=my-order&")
+        );
+        assert!(requests[2].starts_with("GET /api/v3/order?"));
+        assert!(requests[3].starts_with("POST /api/v3/order?"));
+        assert!(requests[3].contains("newClientOrderId=my-order"));
+        assert!(requests[4].starts_with("GET /api/v3/order?"));
+    }
+
+    #[tokio::test]
+    async fn test_error_keeps_response() {
+        let (base_url, requests) = mock_server(vec![
+            (200, r#"{"serverTime":"soon"}"#.into()),
+            (502, "<html>Bad Gateway</html>".into()),
+            (400, r#"{"code":-1121,"msg":"Invalid symbol."}"#.into()),
+        ])
+        .await;
+
+        let client = Client::builder().set_base_url(base_url).build().unwrap();
+
+        let error = client.server_time().await.unwrap_err();
+        assert!(matches!(error, ClientError::Decode(..)));
+        assert!(error.source().unwrap().is::<serde_json::Error>());
+
+        let response = error.response().unwrap();
+        assert_eq!(response.path(), "/api/v3/time");
+        assert_eq!(response.body(), r#"{"serverTime":"soon"}"#);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "application/json"
+        );
+
+        let error = client.server_ping().await.unwrap_err();
+        assert!(matches!(error, ClientError::Http(_)));
+        assert_eq!(error.status(), Some(502));
+        assert_eq!(error.response().unwrap().body(), "<html>Bad Gateway</html>");
+
+        let error = client.price(&"BTCUSD".parse().unwrap()).await.unwrap_err();
+        assert_eq!(error.status(), Some(400));
+        assert_eq!(
+            error.binance_error().unwrap().error_code(),
+            BinanceErrorCode::BadSymbol
+        );
+        assert!(error.source().unwrap().is::<BinanceError>());
+
+        requests.await.unwrap();
+    }
+}
@@ -0,0 +1,236 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use ring::rand::{SecureRandom, SystemRandom};
+
+use super::client::ClientResult;
+use super::error::ClientError;
+use super::error_code::BinanceErrorCode;
+
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(200);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(10);
+const DEFAULT_BUDGET: f64 = 10.0;
+const DEFAULT_BUDGET_RATIO: f64 = 0.2;
+
+// Retries of transport errors, 5xx and -1001/-1003 responses with exponential
+// backoff and jitter. Every request deposits `ratio` into a shared budget and
+// every retry withdraws one, so that a failing API is not hammered further.
+#[derive(Debug)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    max_budget: f64,
+    ratio: f64,
+    budget: Mutex<f64>,
+    random: SystemRandom,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            max_budget: DEFAULT_BUDGET,
+            ratio: DEFAULT_BUDGET_RATIO,
+            budget: Mutex::new(DEFAULT_BUDGET),
+            random: SystemRandom::new(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_max_retries(mut self, value: u32) -> Self {
+        self.max_retries = value;
+
+        self
+    }
+
+    pub fn set_base_delay(mut self, value: Duration) -> Self {
+        self.base_delay = value;
+
+        self
+    }
+
+    pub fn set_max_delay(mut self, value: Duration) -> Self {
+        self.max_delay = value;
+
+        self
+    }
+
+    // At most `max` retries in a burst, refilled by `ratio` per request
+    pub fn set_budget(mut self, max: u32, ratio: f64) -> Self {
+        self.max_budget = max as f64;
+        self.ratio = ratio.max(0.0);
+        self.budget = Mutex::new(max as f64);
+
+        self
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    // Retries currently left in the budget
+    pub fn budget(&self) -> u32 {
+        *self.budget.lock().unwrap() as u32
+    }
+
+    pub(crate) fn deposit(&self) {
+        let mut budget = self.budget.lock().unwrap();
+        *budget = (*budget + self.ratio).min(self.max_budget);
+    }
+
+    pub(crate) fn withdraw(&self) -> bool {
+        let mut budget = self.budget.lock().unwrap();
+
+        match *budget >= 1.0 {
+            true => {
+                *budget -= 1.0;
+                true
+            }
+            false => false,
+        }
+    }
+
+    // Half of the exponential delay plus a random part of the other half
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+
+        let mut bytes = [0; 4];
+        let jitter = match self.random.fill(&mut bytes) {
+            Ok(_) => u32::from_le_bytes(bytes) as f64 / u32::MAX as f64,
+            Err(_) => 0.5,
+        };
+
+        delay / 2 + (delay / 2).mul_f64(jitter)
+    }
+}
+
+// Outcome of an attempt that did not succeed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Failure {
+    // Rejected before being processed, safe to send again
+    NotExecuted,
+
+    // Possibly executed, only safe to send again for queries
+    Unknown,
+
+    // Not worth retrying
+    Fatal,
+}
+
+impl Failure {
+    pub(crate) fn of_transport(error: &reqwest::Error) -> Self {
+        match error {
+            e if e.is_builder() => Self::Fatal,
+            e if e.is_connect() => Self::NotExecuted,
+            _ => Self::Unknown,
+        }
+    }
+
+    // -1001 internal error, -1006 unexpected response and -1007 backend
+    // timeout leave the execution status unknown, as does any 5xx
+    pub(crate) fn of_response(status: u16, code: Option<BinanceErrorCode>) -> Option<Self> {
+        match (status, code) {
+            (_, Some(BinanceErrorCode::TooManyRequests)) => Some(Self::NotExecuted),
+            (_, Some(code)) if code.is_execution_unknown() => Some(Self::Unknown),
+            (500..=599, _) => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn of_error(error: &ClientError) -> Self {
+        match error {
+            ClientError::RateLimited(_) => Self::NotExecuted,
+            _ => Self::Fatal,
+        }
+    }
+}
+
+// Random id matching `^[\.A-Z\:/a-z0-9_-]{1,36}$`
+pub(crate) fn new_client_order_id() -> ClientResult<String> {
+    let mut bytes = [0; 16];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| ClientError::Internal("Random Generator".into()))?;
+
+    Ok(bytes.iter().map(|v| format!("{:02x}", v)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{new_client_order_id, BinanceErrorCode, Failure, RetryPolicy};
+
+    #[test]
+    fn test_retry_policy_delay_and_budget() {
+        let policy = RetryPolicy::new()
+            .set_base_delay(Duration::from_millis(100))
+            .set_max_delay(Duration::from_millis(500))
+            .set_budget(2, 0.5);
+
+        for (attempt, max) in [(0, 100), (1, 200), (2, 400), (3, 500), (30, 500)] {
+            let delay = policy.delay(attempt);
+            assert!(delay >= Duration::from_millis(max / 2));
+            assert!(delay <= Duration::from_millis(max));
+        }
+
+        assert!(policy.withdraw());
+        assert!(policy.withdraw());
+        assert!(!policy.withdraw());
+
+        policy.deposit();
+        assert!(!policy.withdraw());
+        policy.deposit();
+        assert!(policy.withdraw());
+        assert_eq!(policy.budget(), 0);
+
+        (0..10).for_each(|_| policy.deposit());
+        assert_eq!(policy.budget(), 2);
+    }
+
+    #[test]
+    fn test_failure_of_response() {
+        assert_eq!(Failure::of_response(200, None), None);
+        assert_eq!(
+            Failure::of_response(400, Some(BinanceErrorCode::new(-2010, ""))),
+            None
+        );
+        assert_eq!(Failure::of_response(503, None), Some(Failure::Unknown));
+        assert_eq!(
+            Failure::of_response(400, Some(BinanceErrorCode::new(-1007, ""))),
+            Some(Failure::Unknown)
+        );
+        assert_eq!(
+            Failure::of_response(400, Some(BinanceErrorCode::new(-1001, ""))),
+            Some(Failure::Unknown)
+        );
+        assert_eq!(
+            Failure::of_response(400, Some(BinanceErrorCode::new(-1006, ""))),
+            Some(Failure::Unknown)
+        );
+        assert_eq!(
+            Failure::of_response(400, Some(BinanceErrorCode::new(-1003, ""))),
+            Some(Failure::NotExecuted)
+        );
+
+        let id = new_client_order_id().unwrap();
+        assert_eq!(id.len(), 32);
+        assert_ne!(id, new_client_order_id().unwrap());
+    }
+}
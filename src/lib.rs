@@ -3,22 +3,34 @@ pub(crate) mod time;
 
 mod account;
 mod market;
+mod order_list;
 mod spot;
+mod symbol;
 
 pub mod prelude {
     pub use super::http::client::{Client, ClientBuilder, ClientResult};
+    pub use super::http::limit::{
+        BackoffPolicy, RateLimitInterval, RateLimitKey, RateLimitType, RateLimitUsage, RateLimiter,
+    };
+    pub use super::http::retry::RetryPolicy;
+    pub use super::http::sign::{SignFuture, Signer, SigningKey};
+    pub use super::time::{Clock, ClockSync, SystemClock, MAX_RECV_WINDOW};
 }
 
 pub mod error {
-    pub use super::http::error::{BinanceError, ClientError};
+    pub use super::http::error::{BinanceError, ClientError, HttpResponse, RateLimitError};
+    pub use super::http::error_code::{BinanceErrorCode, RejectReason};
 }
 
 pub mod types {
-    pub type Price = String;
-    pub type Asset = String;
-    pub type Symbol = String;
+    // Exact decimals, still (de)serialized as Binance's strings
+    #[cfg(feature = "decimal")]
+    pub type Decimal = rust_decimal::Decimal;
+    #[cfg(not(feature = "decimal"))]
     pub type Decimal = String;
-    pub type Quantity = String;
+
+    pub type Price = Decimal;
+    pub type Quantity = Decimal;
     pub type Commission = Decimal;
 
     pub use super::account::{
@@ -26,10 +38,24 @@ pub mod types {
         SpotCommission, UserAsset,
     };
     pub use super::market::{
-        symbol_filter::*, ExchangeInfo, RateLimit, ServerPing, ServerTime, SymbolInfo, SymbolPrice,
+        symbol_filter::*, AggTrade, AvgPrice, BookTicker, ExchangeInfo, Kline, KlineInterval,
+        OrderBook, OrderBookLevel, PublicTrade, RateLimit, ServerPing, ServerTime, SymbolInfo,
+        SymbolPrice, SymbolStatus, Ticker24hr, TickerType, TickerWindow, TickerWindowSize,
+    };
+    pub use super::order_list::{
+        ContingencyType, ListOrderStatus, ListStatusType, NewOcoOrder, NewOtoOrder, NewOtocoOrder,
+        OrderList, OrderListLeg, OrderListOrder, OrderListReport,
     };
     pub use super::spot::{
-        OrderFill, OrderInfo, OrderResponseFull, OrderSide, OrderStatus, OrderType,
-        SelfTradePreventionMode, TimeInForce, Trade,
+        Allocation, AllocationType, AmendOrderResponse, AmendedOrder, AmendedOrderList,
+        CancelReplaceLeg, CancelReplaceMode, CancelReplaceOrder, CancelReplaceResponse,
+        CancelReplaceResult, CancelRestrictions, CanceledOpenOrder, CanceledOrder, MatchType,
+        NewOrder, OrderCommissionRates, OrderCountUsage, OrderFill, OrderInfo,
+        OrderRateLimitExceededMode, OrderResponse, OrderResponseAck, OrderResponseFull,
+        OrderResponseResult, OrderResponseType, OrderSide, OrderStatus, OrderType, PreventedMatch,
+        PreventedMatchQuery, SelfTradePreventionMode, TestOrderResponse, TimeInForce, Trade,
+        WorkingFloor,
     };
+    pub use super::symbol::{Asset, Symbol, SymbolRegistry};
+    pub use super::time::{TimeUnit, Timestamp};
 }
@@ -1,9 +1,17 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 use crate::{
-    http::client::{Client, ClientResult},
-    types::{Price, Symbol},
+    http::{
+        client::{Client, ClientResult},
+        error::ClientError,
+        limit::{RateLimitInterval, RateLimitType},
+    },
+    types::{
+        Asset, Decimal, OrderType, Price, Quantity, SelfTradePreventionMode, Symbol, Timestamp,
+    },
 };
 
 impl Client {
@@ -30,7 +38,11 @@ impl Client {
             query_pairs.append_pair("symbol", symbol);
         }
 
-        self.build_request_get(url).send().await
+        let exchange_info: ExchangeInfo =
+            self.build_request_get(url).with_weight(20).send().await?;
+        self.rate_limiter.set_limits(&exchange_info.rate_limits);
+
+        Ok(exchange_info)
     }
 
     pub async fn exchange_infos(
@@ -48,7 +60,11 @@ impl Client {
             }
         }
 
-        self.build_request_get(url).send().await
+        let exchange_info: ExchangeInfo =
+            self.build_request_get(url).with_weight(20).send().await?;
+        self.rate_limiter.set_limits(&exchange_info.rate_limits);
+
+        Ok(exchange_info)
     }
 
     pub async fn price(&self, symbol: &Symbol) -> ClientResult<SymbolPrice> {
@@ -60,7 +76,7 @@ impl Client {
             query_pairs.append_pair("symbol", symbol);
         }
 
-        self.build_request_get(url).send().await
+        self.build_request_get(url).with_weight(2).send().await
     }
 
     pub async fn prices(&self, symbols: Option<&Vec<Symbol>>) -> ClientResult<Vec<SymbolPrice>> {
@@ -75,7 +91,632 @@ impl Client {
             }
         }
 
-        self.build_request_get(url).send().await
+        self.build_request_get(url).with_weight(4).send().await
+    }
+
+    pub async fn ticker_24hr(
+        &self,
+        symbol: &Symbol,
+        ticker_type: Option<TickerType>,
+    ) -> ClientResult<Ticker24hr> {
+        self.request_ticker_24hr(Some(symbol), None, ticker_type)
+            .await
+    }
+
+    pub async fn tickers_24hr(
+        &self,
+        symbols: Option<&Vec<Symbol>>,
+        ticker_type: Option<TickerType>,
+    ) -> ClientResult<Vec<Ticker24hr>> {
+        self.request_ticker_24hr(None, symbols, ticker_type).await
+    }
+
+    async fn request_ticker_24hr<T>(
+        &self,
+        symbol: Option<&Symbol>,
+        symbols: Option<&Vec<Symbol>>,
+        ticker_type: Option<TickerType>,
+    ) -> ClientResult<T>
+    where
+        for<'a> T: Deserialize<'a>,
+    {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/ticker/24hr");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            if let Some(value) = symbol {
+                query_pairs.append_pair("symbol", value);
+            }
+
+            if let Some(value) = symbols {
+                query_pairs.append_pair("symbols", &serde_json::to_string(value)?);
+            }
+
+            if let Some(value) = ticker_type {
+                query_pairs.append_pair("type", value.as_str());
+            }
+        }
+
+        let weight = match (symbol, symbols.map(|v| v.len())) {
+            (Some(_), _) => 2,
+            (None, Some(1..=20)) => 2,
+            (None, Some(21..=100)) => 40,
+            _ => 80,
+        };
+
+        self.build_request_get(url).with_weight(weight).send().await
+    }
+
+    pub async fn ticker_window(
+        &self,
+        symbol: &Symbol,
+        window_size: Option<TickerWindowSize>,
+        ticker_type: Option<TickerType>,
+    ) -> ClientResult<TickerWindow> {
+        let window_size = window_size.map(|v| v.param()).transpose()?;
+
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/ticker");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+
+            if let Some(value) = window_size {
+                query_pairs.append_pair("windowSize", &value);
+            }
+
+            if let Some(value) = ticker_type {
+                query_pairs.append_pair("type", value.as_str());
+            }
+        }
+
+        self.build_request_get(url).with_weight(4).send().await
+    }
+
+    pub async fn tickers_window(
+        &self,
+        symbols: &Vec<Symbol>,
+        window_size: Option<TickerWindowSize>,
+        ticker_type: Option<TickerType>,
+    ) -> ClientResult<Vec<TickerWindow>> {
+        let window_size = window_size.map(|v| v.param()).transpose()?;
+
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/ticker");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbols", &serde_json::to_string(symbols)?);
+
+            if let Some(value) = window_size {
+                query_pairs.append_pair("windowSize", &value);
+            }
+
+            if let Some(value) = ticker_type {
+                query_pairs.append_pair("type", value.as_str());
+            }
+        }
+
+        // Each symbol weighs 4, capped at 200
+        let weight = (4 * symbols.len() as u32).min(200);
+
+        self.build_request_get(url).with_weight(weight).send().await
+    }
+
+    pub async fn ticker_trading_day(
+        &self,
+        symbol: &Symbol,
+        time_zone: Option<&String>,
+        ticker_type: Option<TickerType>,
+    ) -> ClientResult<TickerWindow> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/ticker/tradingDay");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+
+            if let Some(value) = time_zone {
+                query_pairs.append_pair("timeZone", value);
+            }
+
+            if let Some(value) = ticker_type {
+                query_pairs.append_pair("type", value.as_str());
+            }
+        }
+
+        self.build_request_get(url).with_weight(4).send().await
+    }
+
+    pub async fn tickers_trading_day(
+        &self,
+        symbols: &Vec<Symbol>,
+        time_zone: Option<&String>,
+        ticker_type: Option<TickerType>,
+    ) -> ClientResult<Vec<TickerWindow>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/ticker/tradingDay");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbols", &serde_json::to_string(symbols)?);
+
+            if let Some(value) = time_zone {
+                query_pairs.append_pair("timeZone", value);
+            }
+
+            if let Some(value) = ticker_type {
+                query_pairs.append_pair("type", value.as_str());
+            }
+        }
+
+        // Each symbol weighs 4, capped at 200
+        let weight = (4 * symbols.len() as u32).min(200);
+
+        self.build_request_get(url).with_weight(weight).send().await
+    }
+
+    pub async fn book_ticker(&self, symbol: &Symbol) -> ClientResult<BookTicker> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/ticker/bookTicker");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            query_pairs.append_pair("symbol", symbol);
+        }
+
+        self.build_request_get(url).with_weight(2).send().await
+    }
+
+    pub async fn book_tickers(
+        &self,
+        symbols: Option<&Vec<Symbol>>,
+    ) -> ClientResult<Vec<BookTicker>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/ticker/bookTicker");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            if let Some(value) = symbols {
+                query_pairs.append_pair("symbols", &serde_json::to_string(value)?);
+            }
+        }
+
+        self.build_request_get(url).with_weight(4).send().await
+    }
+
+    pub async fn avg_price(&self, symbol: &Symbol) -> ClientResult<AvgPrice> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/avgPrice");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            query_pairs.append_pair("symbol", symbol);
+        }
+
+        self.build_request_get(url).with_weight(2).send().await
+    }
+
+    pub async fn order_book(&self, symbol: &Symbol, limit: Option<u16>) -> ClientResult<OrderBook> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/depth");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+
+            if let Some(value) = limit {
+                query_pairs.append_pair("limit", &value.to_string());
+            }
+        }
+
+        let weight = match limit.unwrap_or(100) {
+            0..=100 => 5,
+            101..=500 => 25,
+            501..=1000 => 50,
+            _ => 250,
+        };
+
+        self.build_request_get(url).with_weight(weight).send().await
+    }
+
+    pub async fn recent_trades(
+        &self,
+        symbol: &Symbol,
+        limit: Option<u16>,
+    ) -> ClientResult<Vec<PublicTrade>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/trades");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+
+            if let Some(value) = limit {
+                query_pairs.append_pair("limit", &value.to_string());
+            }
+        }
+
+        self.build_request_get(url).with_weight(25).send().await
+    }
+
+    pub async fn historical_trades(
+        &self,
+        symbol: &Symbol,
+        from_id: Option<i64>,
+        limit: Option<u16>,
+    ) -> ClientResult<Vec<PublicTrade>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/historicalTrades");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+
+            if let Some(value) = from_id {
+                query_pairs.append_pair("fromId", &value.to_string());
+            }
+
+            if let Some(value) = limit {
+                query_pairs.append_pair("limit", &value.to_string());
+            }
+        }
+
+        self.build_api_key_request_get(url)?
+            .with_weight(25)
+            .send()
+            .await
+    }
+
+    pub async fn agg_trades(
+        &self,
+        symbol: &Symbol,
+        from_id: Option<i64>,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+        limit: Option<u16>,
+    ) -> ClientResult<Vec<AggTrade>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/aggTrades");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+
+            if let Some(value) = from_id {
+                query_pairs.append_pair("fromId", &value.to_string());
+            }
+
+            if let Some(value) = start_time {
+                query_pairs.append_pair("startTime", &value.to_string());
+            }
+
+            if let Some(value) = end_time {
+                query_pairs.append_pair("endTime", &value.to_string());
+            }
+
+            if let Some(value) = limit {
+                query_pairs.append_pair("limit", &value.to_string());
+            }
+        }
+
+        self.build_request_get(url).with_weight(4).send().await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn klines(
+        &self,
+        symbol: &Symbol,
+        interval: KlineInterval,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+        time_zone: Option<&String>,
+        limit: Option<u16>,
+    ) -> ClientResult<Vec<Kline>> {
+        self.request_klines(
+            "/api/v3/klines",
+            symbol,
+            interval,
+            start_time,
+            end_time,
+            time_zone,
+            limit,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn ui_klines(
+        &self,
+        symbol: &Symbol,
+        interval: KlineInterval,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+        time_zone: Option<&String>,
+        limit: Option<u16>,
+    ) -> ClientResult<Vec<Kline>> {
+        self.request_klines(
+            "/api/v3/uiKlines",
+            symbol,
+            interval,
+            start_time,
+            end_time,
+            time_zone,
+            limit,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn request_klines(
+        &self,
+        path: &str,
+        symbol: &Symbol,
+        interval: KlineInterval,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+        time_zone: Option<&String>,
+        limit: Option<u16>,
+    ) -> ClientResult<Vec<Kline>> {
+        let mut url = self.base_url()?;
+        url.set_path(path);
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+            query_pairs.append_pair("interval", interval.as_str());
+
+            if let Some(value) = start_time {
+                query_pairs.append_pair("startTime", &value.to_string());
+            }
+
+            if let Some(value) = end_time {
+                query_pairs.append_pair("endTime", &value.to_string());
+            }
+
+            if let Some(value) = time_zone {
+                query_pairs.append_pair("timeZone", value);
+            }
+
+            if let Some(value) = limit {
+                query_pairs.append_pair("limit", &value.to_string());
+            }
+        }
+
+        self.build_request_get(url).with_weight(2).send().await
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicTrade {
+    pub id: i64,
+    pub price: Price,
+    pub qty: Quantity,
+    pub time: Timestamp,
+
+    #[serde(rename = "quoteQty")]
+    pub quote_qty: Quantity,
+
+    #[serde(rename = "isBuyerMaker")]
+    pub is_buyer_maker: bool,
+
+    #[serde(rename = "isBestMatch")]
+    pub is_best_match: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggTrade {
+    #[serde(rename = "a")]
+    pub id: i64,
+
+    #[serde(rename = "p")]
+    pub price: Price,
+
+    #[serde(rename = "q")]
+    pub qty: Quantity,
+
+    #[serde(rename = "f")]
+    pub first_trade_id: i64,
+
+    #[serde(rename = "l")]
+    pub last_trade_id: i64,
+
+    #[serde(rename = "T")]
+    pub time: Timestamp,
+
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+
+    #[serde(rename = "M")]
+    pub is_best_match: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KlineInterval {
+    #[serde(rename = "1s")]
+    OneSecond,
+
+    #[serde(rename = "1m")]
+    OneMinute,
+
+    #[serde(rename = "3m")]
+    ThreeMinutes,
+
+    #[serde(rename = "5m")]
+    FiveMinutes,
+
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+
+    #[serde(rename = "30m")]
+    ThirtyMinutes,
+
+    #[serde(rename = "1h")]
+    OneHour,
+
+    #[serde(rename = "2h")]
+    TwoHours,
+
+    #[serde(rename = "4h")]
+    FourHours,
+
+    #[serde(rename = "6h")]
+    SixHours,
+
+    #[serde(rename = "8h")]
+    EightHours,
+
+    #[serde(rename = "12h")]
+    TwelveHours,
+
+    #[serde(rename = "1d")]
+    OneDay,
+
+    #[serde(rename = "3d")]
+    ThreeDays,
+
+    #[serde(rename = "1w")]
+    OneWeek,
+
+    #[serde(rename = "1M")]
+    OneMonth,
+}
+
+impl KlineInterval {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::OneSecond => "1s",
+            Self::OneMinute => "1m",
+            Self::ThreeMinutes => "3m",
+            Self::FiveMinutes => "5m",
+            Self::FifteenMinutes => "15m",
+            Self::ThirtyMinutes => "30m",
+            Self::OneHour => "1h",
+            Self::TwoHours => "2h",
+            Self::FourHours => "4h",
+            Self::SixHours => "6h",
+            Self::EightHours => "8h",
+            Self::TwelveHours => "12h",
+            Self::OneDay => "1d",
+            Self::ThreeDays => "3d",
+            Self::OneWeek => "1w",
+            Self::OneMonth => "1M",
+        }
+    }
+}
+
+type KlineRow = (
+    Timestamp,
+    Price,
+    Price,
+    Price,
+    Price,
+    Quantity,
+    Timestamp,
+    Quantity,
+    u64,
+    Quantity,
+    Quantity,
+    Decimal,
+);
+
+// Binance encodes each kline as a 12-element array
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "KlineRow", into = "KlineRow")]
+pub struct Kline {
+    pub open_time: Timestamp,
+    pub open: Price,
+    pub high: Price,
+    pub low: Price,
+    pub close: Price,
+    pub volume: Quantity,
+    pub close_time: Timestamp,
+    pub quote_asset_volume: Quantity,
+    pub number_of_trades: u64,
+    pub taker_buy_base_asset_volume: Quantity,
+    pub taker_buy_quote_asset_volume: Quantity,
+
+    // Unused field, kept so the row serializes back unchanged
+    pub ignore: Decimal,
+}
+
+impl From<KlineRow> for Kline {
+    fn from(value: KlineRow) -> Self {
+        Self {
+            open_time: value.0,
+            open: value.1,
+            high: value.2,
+            low: value.3,
+            close: value.4,
+            volume: value.5,
+            close_time: value.6,
+            quote_asset_volume: value.7,
+            number_of_trades: value.8,
+            taker_buy_base_asset_volume: value.9,
+            taker_buy_quote_asset_volume: value.10,
+            ignore: value.11,
+        }
+    }
+}
+
+impl From<Kline> for KlineRow {
+    fn from(value: Kline) -> Self {
+        (
+            value.open_time,
+            value.open,
+            value.high,
+            value.low,
+            value.close,
+            value.volume,
+            value.close_time,
+            value.quote_asset_volume,
+            value.number_of_trades,
+            value.taker_buy_base_asset_volume,
+            value.taker_buy_quote_asset_volume,
+            value.ignore,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBook {
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: u64,
+
+    pub bids: Vec<OrderBookLevel>,
+    pub asks: Vec<OrderBookLevel>,
+}
+
+// Binance encodes each level as a `["price", "quantity"]` pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "(Price, Quantity)", into = "(Price, Quantity)")]
+pub struct OrderBookLevel {
+    pub price: Price,
+    pub quantity: Quantity,
+}
+
+impl From<(Price, Quantity)> for OrderBookLevel {
+    fn from((price, quantity): (Price, Quantity)) -> Self {
+        Self { price, quantity }
+    }
+}
+
+impl From<OrderBookLevel> for (Price, Quantity) {
+    fn from(value: OrderBookLevel) -> Self {
+        (value.price, value.quantity)
     }
 }
 
@@ -85,10 +726,203 @@ pub struct SymbolPrice {
     pub price: Price,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TickerType {
+    #[serde(rename = "FULL")]
+    Full,
+
+    #[serde(rename = "MINI")]
+    Mini,
+}
+
+impl TickerType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Full => "FULL",
+            Self::Mini => "MINI",
+        }
+    }
+}
+
+// Binance accepts 1m-59m, 1h-23h and 1d-7d
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickerWindowSize {
+    Minutes(u8),
+    Hours(u8),
+    Days(u8),
+}
+
+impl Display for TickerWindowSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Minutes(value) => write!(f, "{}m", value),
+            Self::Hours(value) => write!(f, "{}h", value),
+            Self::Days(value) => write!(f, "{}d", value),
+        }
+    }
+}
+
+impl TickerWindowSize {
+    pub(crate) fn param(&self) -> ClientResult<String> {
+        let valid = match *self {
+            Self::Minutes(value) => (1..=59).contains(&value),
+            Self::Hours(value) => (1..=23).contains(&value),
+            Self::Days(value) => (1..=7).contains(&value),
+        };
+
+        if !valid {
+            let msg = format!(
+                "windowSize must be within 1m-59m, 1h-23h or 1d-7d, got {}",
+                self
+            );
+            return Err(ClientError::InvalidParameter(msg));
+        }
+
+        Ok(self.to_string())
+    }
+}
+
+// Fields marked optional are only present in the FULL response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ticker24hr {
+    pub symbol: Symbol,
+
+    #[serde(rename = "priceChange", skip_serializing_if = "Option::is_none")]
+    pub price_change: Option<Decimal>,
+
+    #[serde(rename = "priceChangePercent", skip_serializing_if = "Option::is_none")]
+    pub price_change_percent: Option<Decimal>,
+
+    #[serde(rename = "weightedAvgPrice", skip_serializing_if = "Option::is_none")]
+    pub weighted_avg_price: Option<Price>,
+
+    #[serde(rename = "prevClosePrice", skip_serializing_if = "Option::is_none")]
+    pub prev_close_price: Option<Price>,
+
+    #[serde(rename = "lastPrice")]
+    pub last_price: Price,
+
+    #[serde(rename = "lastQty", skip_serializing_if = "Option::is_none")]
+    pub last_qty: Option<Quantity>,
+
+    #[serde(rename = "bidPrice", skip_serializing_if = "Option::is_none")]
+    pub bid_price: Option<Price>,
+
+    #[serde(rename = "bidQty", skip_serializing_if = "Option::is_none")]
+    pub bid_qty: Option<Quantity>,
+
+    #[serde(rename = "askPrice", skip_serializing_if = "Option::is_none")]
+    pub ask_price: Option<Price>,
+
+    #[serde(rename = "askQty", skip_serializing_if = "Option::is_none")]
+    pub ask_qty: Option<Quantity>,
+
+    #[serde(rename = "openPrice")]
+    pub open_price: Price,
+
+    #[serde(rename = "highPrice")]
+    pub high_price: Price,
+
+    #[serde(rename = "lowPrice")]
+    pub low_price: Price,
+
+    pub volume: Quantity,
+
+    #[serde(rename = "quoteVolume")]
+    pub quote_volume: Quantity,
+
+    #[serde(rename = "openTime")]
+    pub open_time: Timestamp,
+
+    #[serde(rename = "closeTime")]
+    pub close_time: Timestamp,
+
+    #[serde(rename = "firstId")]
+    pub first_id: i64,
+
+    #[serde(rename = "lastId")]
+    pub last_id: i64,
+
+    pub count: u64,
+}
+
+// Returned by the rolling window and trading day tickers, fields marked
+// optional are only present in the FULL response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TickerWindow {
+    pub symbol: Symbol,
+
+    #[serde(rename = "priceChange", skip_serializing_if = "Option::is_none")]
+    pub price_change: Option<Decimal>,
+
+    #[serde(rename = "priceChangePercent", skip_serializing_if = "Option::is_none")]
+    pub price_change_percent: Option<Decimal>,
+
+    #[serde(rename = "weightedAvgPrice", skip_serializing_if = "Option::is_none")]
+    pub weighted_avg_price: Option<Price>,
+
+    #[serde(rename = "openPrice")]
+    pub open_price: Price,
+
+    #[serde(rename = "highPrice")]
+    pub high_price: Price,
+
+    #[serde(rename = "lowPrice")]
+    pub low_price: Price,
+
+    #[serde(rename = "lastPrice")]
+    pub last_price: Price,
+
+    pub volume: Quantity,
+
+    #[serde(rename = "quoteVolume")]
+    pub quote_volume: Quantity,
+
+    #[serde(rename = "openTime")]
+    pub open_time: Timestamp,
+
+    #[serde(rename = "closeTime")]
+    pub close_time: Timestamp,
+
+    #[serde(rename = "firstId")]
+    pub first_id: i64,
+
+    #[serde(rename = "lastId")]
+    pub last_id: i64,
+
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookTicker {
+    pub symbol: Symbol,
+
+    #[serde(rename = "bidPrice")]
+    pub bid_price: Price,
+
+    #[serde(rename = "bidQty")]
+    pub bid_qty: Quantity,
+
+    #[serde(rename = "askPrice")]
+    pub ask_price: Price,
+
+    #[serde(rename = "askQty")]
+    pub ask_qty: Quantity,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvgPrice {
+    pub mins: u32,
+    pub price: Price,
+
+    #[serde(rename = "closeTime")]
+    pub close_time: Timestamp,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerTime {
     #[serde(rename = "serverTime")]
-    pub server_time: u128,
+    pub server_time: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -99,7 +933,7 @@ pub struct ExchangeInfo {
     pub timezone: String,
 
     #[serde(rename = "serverTime")]
-    pub server_time: u128,
+    pub server_time: Timestamp,
 
     #[serde(rename = "rateLimits")]
     pub rate_limits: Vec<RateLimit>,
@@ -113,9 +947,9 @@ pub struct ExchangeInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RateLimit {
     #[serde(rename = "rateLimitType")]
-    pub rate_limit_type: String,
+    pub rate_limit_type: RateLimitType,
 
-    pub interval: String,
+    pub interval: RateLimitInterval,
 
     #[serde(rename = "intervalNum")]
     pub interval_num: u8,
@@ -127,16 +961,16 @@ pub struct RateLimit {
 pub struct SymbolInfo {
     pub symbol: Symbol,
 
-    pub status: String,
+    pub status: SymbolStatus,
 
     #[serde(rename = "baseAsset")]
-    pub base_asset: Symbol,
+    pub base_asset: Asset,
 
     #[serde(rename = "baseAssetPrecision")]
     pub base_asset_precision: u8,
 
     #[serde(rename = "quoteAsset")]
-    pub quote_asset: Symbol,
+    pub quote_asset: Asset,
 
     #[serde(rename = "quotePrecision")]
     pub quote_precision: u8,
@@ -151,7 +985,7 @@ pub struct SymbolInfo {
     pub quote_commission_precision: u8,
 
     #[serde(rename = "orderTypes")]
-    pub order_types: Vec<String>,
+    pub order_types: Vec<OrderType>,
 
     #[serde(rename = "icebergAllowed")]
     pub iceberg_allowed: bool,
@@ -185,14 +1019,57 @@ pub struct SymbolInfo {
     pub permission_sets: Vec<Vec<String>>,
 
     #[serde(rename = "defaultSelfTradePreventionMode")]
-    pub default_self_trade_prevention_mode: String,
+    pub default_self_trade_prevention_mode: SelfTradePreventionMode,
 
     #[serde(rename = "allowedSelfTradePreventionModes")]
-    pub allowed_self_trade_prevention_modes: Vec<String>,
+    pub allowed_self_trade_prevention_modes: Vec<SelfTradePreventionMode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SymbolStatus {
+    #[serde(rename = "PRE_TRADING")]
+    PreTrading,
+
+    #[serde(rename = "TRADING")]
+    Trading,
+
+    #[serde(rename = "POST_TRADING")]
+    PostTrading,
+
+    #[serde(rename = "END_OF_DAY")]
+    EndOfDay,
+
+    #[serde(rename = "HALT")]
+    Halt,
+
+    #[serde(rename = "AUCTION_MATCH")]
+    AuctionMatch,
+
+    #[serde(rename = "BREAK")]
+    Break,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl SymbolStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::PreTrading => "PRE_TRADING",
+            Self::Trading => "TRADING",
+            Self::PostTrading => "POST_TRADING",
+            Self::EndOfDay => "END_OF_DAY",
+            Self::Halt => "HALT",
+            Self::AuctionMatch => "AUCTION_MATCH",
+            Self::Break => "BREAK",
+            Self::Unknown(v) => v,
+        }
+    }
 }
 
 pub mod symbol_filter {
     use serde::{Deserialize, Serialize};
+    use serde_json::Value as JsonValue;
 
     use crate::types::{Decimal, Price, Quantity};
 
@@ -403,12 +1280,17 @@ pub mod symbol_filter {
 
         #[serde(rename = "TRAILING_DELTA")]
         TrailingDelta(SymbolTrailingDeltaFilter),
+
+        // Filters added by Binance after this version, as sent including
+        // their `filterType`
+        #[serde(untagged)]
+        Unknown(JsonValue),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::http::client::tests::client;
+    use crate::http::client::tests::{client, client_with_key_secret};
 
     #[tokio::test]
     async fn test_server_time() {
@@ -425,14 +1307,17 @@ mod tests {
     #[tokio::test]
     async fn test_price() {
         let client = client();
-        client.price(&"BTCUSDT".into()).await.unwrap();
+        client.price(&"BTCUSDT".parse().unwrap()).await.unwrap();
     }
 
     #[tokio::test]
     async fn test_prices() {
         let client = client();
         client
-            .prices(Some(&vec!["BTCUSDT".into(), "ETHUSDT".into()]))
+            .prices(Some(&vec![
+                "BTCUSDT".parse().unwrap(),
+                "ETHUSDT".parse().unwrap(),
+            ]))
             .await
             .unwrap();
     }
@@ -440,14 +1325,169 @@ mod tests {
     #[tokio::test]
     async fn test_exchange_info() {
         let client = client();
-        client.exchange_info(&"BTCUSDT".into()).await.unwrap();
+        client
+            .exchange_info(&"BTCUSDT".parse().unwrap())
+            .await
+            .unwrap();
     }
 
     #[tokio::test]
     async fn test_exchange_infos() {
         let client = client();
         client
-            .exchange_infos(Some(&vec!["BTCUSDT".into(), "ETHUSDT".into()]))
+            .exchange_infos(Some(&vec![
+                "BTCUSDT".parse().unwrap(),
+                "ETHUSDT".parse().unwrap(),
+            ]))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ticker_24hr() {
+        use super::TickerType;
+
+        let client = client();
+        client
+            .ticker_24hr(&"BTCUSDT".parse().unwrap(), Some(TickerType::Mini))
+            .await
+            .unwrap();
+        client
+            .tickers_24hr(
+                Some(&vec![
+                    "BTCUSDT".parse().unwrap(),
+                    "ETHUSDT".parse().unwrap(),
+                ]),
+                Some(TickerType::Full),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ticker_window() {
+        use super::{TickerType, TickerWindowSize};
+
+        let client = client();
+        client
+            .ticker_window(
+                &"BTCUSDT".parse().unwrap(),
+                Some(TickerWindowSize::Hours(4)),
+                Some(TickerType::Full),
+            )
+            .await
+            .unwrap();
+        client
+            .tickers_window(
+                &vec!["BTCUSDT".parse().unwrap(), "ETHUSDT".parse().unwrap()],
+                Some(TickerWindowSize::Days(1)),
+                Some(TickerType::Mini),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ticker_trading_day() {
+        let client = client();
+        client
+            .ticker_trading_day(&"BTCUSDT".parse().unwrap(), None, None)
+            .await
+            .unwrap();
+        client
+            .tickers_trading_day(
+                &vec!["BTCUSDT".parse().unwrap(), "ETHUSDT".parse().unwrap()],
+                Some(&"+08:00".into()),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_book_ticker() {
+        let client = client();
+        client
+            .book_ticker(&"BTCUSDT".parse().unwrap())
+            .await
+            .unwrap();
+        client.book_tickers(None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_avg_price() {
+        let client = client();
+        client.avg_price(&"BTCUSDT".parse().unwrap()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_order_book() {
+        let client = client();
+        client
+            .order_book(&"BTCUSDT".parse().unwrap(), Some(5))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_recent_trades() {
+        let client = client();
+        client
+            .recent_trades(&"BTCUSDT".parse().unwrap(), Some(10))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_historical_trades() {
+        let client = client_with_key_secret();
+        client
+            .historical_trades(&"BTCUSDT".parse().unwrap(), None, Some(10))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_agg_trades() {
+        let client = client();
+        client
+            .agg_trades(&"BTCUSDT".parse().unwrap(), None, None, None, Some(10))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_klines() {
+        use super::KlineInterval;
+
+        let client = client();
+        client
+            .klines(
+                &"BTCUSDT".parse().unwrap(),
+                KlineInterval::OneHour,
+                None,
+                None,
+                None,
+                Some(10),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ui_klines() {
+        use super::KlineInterval;
+
+        let client = client();
+        client
+            .ui_klines(
+                &"BTCUSDT".parse().unwrap(),
+                KlineInterval::OneDay,
+                None,
+                None,
+                Some(&"+08:00".into()),
+                Some(10),
+            )
             .await
             .unwrap();
     }
@@ -468,9 +1508,9 @@ mod tests {
         for i in filters.iter() {
             match i {
                 SymbolFilter::PriceFilter(v) => {
-                    assert_eq!(v.max_price, "100000.00000000");
-                    assert_eq!(v.min_price, "0.00000100");
-                    assert_eq!(v.tick_size, "0.00000100")
+                    assert_eq!(v.max_price.to_string(), "100000.00000000");
+                    assert_eq!(v.min_price.to_string(), "0.00000100");
+                    assert_eq!(v.tick_size.to_string(), "0.00000100")
                 }
                 _ => continue,
             }
@@ -479,4 +1519,145 @@ mod tests {
 
         assert_eq!(serde_json::to_string(&filters).unwrap(), json_data);
     }
+
+    #[test]
+    fn test_symbol_info_unknown_values_serde() {
+        use super::{symbol_filter::SymbolFilter, SymbolInfo, SymbolStatus};
+        use crate::types::{OrderType, SelfTradePreventionMode};
+
+        let json_data = r#"{"symbol":"ETHBTC","status":"DELISTING","baseAsset":"ETH","baseAssetPrecision":8,"quoteAsset":"BTC","quotePrecision":8,"quoteAssetPrecision":8,"baseCommissionPrecision":8,"quoteCommissionPrecision":8,"orderTypes":["LIMIT","PEGGED"],"icebergAllowed":true,"ocoAllowed":true,"otoAllowed":true,"quoteOrderQtyMarketAllowed":true,"allowTrailingStop":false,"cancelReplaceAllowed":false,"isSpotTradingAllowed":true,"isMarginTradingAllowed":true,"filters":[{"filterType":"MAX_NUM_ORDERS","maxNumOrders":25},{"filterType":"MAX_NUM_ORDER_LISTS","maxNumOrderLists":20}],"permissions":[],"permissionSets":[["SPOT"]],"defaultSelfTradePreventionMode":"EXPIRE_MAKER","allowedSelfTradePreventionModes":["NONE","DECREMENT"]}"#;
+
+        let info: SymbolInfo = serde_json::from_str(json_data).unwrap();
+        assert_eq!(info.status, SymbolStatus::Unknown("DELISTING".into()));
+        assert_eq!(info.status.as_str(), "DELISTING");
+        assert_eq!(
+            info.order_types,
+            [OrderType::Limit, OrderType::Unknown("PEGGED".into())]
+        );
+        assert_eq!(
+            info.default_self_trade_prevention_mode,
+            SelfTradePreventionMode::ExpireMaker
+        );
+        assert_eq!(
+            info.allowed_self_trade_prevention_modes[1].as_str(),
+            "DECREMENT"
+        );
+        assert!(matches!(info.filters[0], SymbolFilter::MaxNumOrders(_)));
+        assert!(
+            matches!(&info.filters[1], SymbolFilter::Unknown(v) if v["maxNumOrderLists"] == 20)
+        );
+
+        assert_eq!(serde_json::to_string(&info).unwrap(), json_data);
+    }
+
+    #[test]
+    fn test_order_book_serde() {
+        use super::OrderBook;
+
+        let json_data = r#"{"lastUpdateId":1027024,"bids":[["4.00000000","431.00000000"]],"asks":[["4.00000200","12.00000000"],["4.00000300","1.50000000"]]}"#;
+
+        let book: OrderBook = serde_json::from_str(json_data).unwrap();
+        assert_eq!(book.last_update_id, 1027024);
+        assert_eq!(book.bids[0].price.to_string(), "4.00000000");
+        assert_eq!(book.bids[0].quantity.to_string(), "431.00000000");
+        assert_eq!(book.asks.len(), 2);
+
+        assert_eq!(serde_json::to_string(&book).unwrap(), json_data);
+    }
+
+    #[test]
+    fn test_kline_serde() {
+        use super::Kline;
+
+        let json_data = r#"[[1499040000000,"0.01634790","0.80000000","0.01575800","0.01577100","148976.11427815",1499644799999,"2434.19055334",308,"1756.87402397","28.46694368","0"]]"#;
+
+        let klines: Vec<Kline> = serde_json::from_str(json_data).unwrap();
+        assert_eq!(klines[0].open_time.as_millis(), 1499040000000);
+        assert_eq!(klines[0].open.to_string(), "0.01634790");
+        assert_eq!(klines[0].close_time.as_millis(), 1499644799999);
+        assert_eq!(klines[0].number_of_trades, 308);
+        assert_eq!(
+            klines[0].taker_buy_quote_asset_volume.to_string(),
+            "28.46694368"
+        );
+
+        assert_eq!(serde_json::to_string(&klines).unwrap(), json_data);
+    }
+
+    #[test]
+    fn test_agg_trade_serde() {
+        use super::AggTrade;
+
+        let json_data = r#"[{"a":26129,"p":"0.01633102","q":"4.70443515","f":27781,"l":27781,"T":1498793709153,"m":true,"M":true}]"#;
+
+        let trades: Vec<AggTrade> = serde_json::from_str(json_data).unwrap();
+        assert_eq!(trades[0].id, 26129);
+        assert_eq!(trades[0].first_trade_id, 27781);
+        assert!(trades[0].is_buyer_maker);
+
+        assert_eq!(serde_json::to_string(&trades).unwrap(), json_data);
+    }
+
+    #[test]
+    fn test_ticker_24hr_serde() {
+        use super::Ticker24hr;
+
+        let full = r#"{"symbol":"BNBBTC","priceChange":"-94.99999800","priceChangePercent":"-95.960","weightedAvgPrice":"0.29628482","prevClosePrice":"0.10002000","lastPrice":"4.00000200","lastQty":"200.00000000","bidPrice":"4.00000000","bidQty":"100.00000000","askPrice":"4.00000200","askQty":"100.00000000","openPrice":"99.00000000","highPrice":"100.00000000","lowPrice":"0.10000000","volume":"8913.30000000","quoteVolume":"15.30000000","openTime":1499783499040,"closeTime":1499869899040,"firstId":28385,"lastId":28460,"count":76}"#;
+        let mini = r#"{"symbol":"BNBBTC","lastPrice":"4.00000200","openPrice":"99.00000000","highPrice":"100.00000000","lowPrice":"0.10000000","volume":"8913.30000000","quoteVolume":"15.30000000","openTime":1499783499040,"closeTime":1499869899040,"firstId":28385,"lastId":28460,"count":76}"#;
+
+        let ticker: Ticker24hr = serde_json::from_str(full).unwrap();
+        assert_eq!(
+            ticker
+                .bid_price
+                .as_ref()
+                .map(ToString::to_string)
+                .as_deref(),
+            Some("4.00000000")
+        );
+        assert_eq!(serde_json::to_string(&ticker).unwrap(), full);
+
+        let ticker: Ticker24hr = serde_json::from_str(mini).unwrap();
+        assert!(ticker.bid_price.is_none());
+        assert_eq!(serde_json::to_string(&ticker).unwrap(), mini);
+    }
+
+    #[test]
+    fn test_ticker_window_size_display() {
+        use super::TickerWindowSize;
+
+        assert_eq!(TickerWindowSize::Minutes(15).to_string(), "15m");
+        assert_eq!(TickerWindowSize::Hours(4).to_string(), "4h");
+        assert_eq!(TickerWindowSize::Days(7).to_string(), "7d");
+    }
+
+    #[tokio::test]
+    async fn test_ticker_window_size_validation() {
+        use super::TickerWindowSize;
+        use crate::http::error::ClientError;
+
+        assert_eq!(TickerWindowSize::Minutes(59).param().unwrap(), "59m");
+        assert_eq!(TickerWindowSize::Hours(23).param().unwrap(), "23h");
+
+        let client = client();
+        let symbol = "BTCUSDT".parse().unwrap();
+
+        for window_size in [
+            TickerWindowSize::Minutes(0),
+            TickerWindowSize::Minutes(60),
+            TickerWindowSize::Hours(24),
+            TickerWindowSize::Days(8),
+        ] {
+            let error = client
+                .ticker_window(&symbol, Some(window_size), None)
+                .await
+                .unwrap_err();
+            assert!(matches!(error, ClientError::InvalidParameter(_)));
+
+            let error = client
+                .tickers_window(&vec![symbol.clone()], Some(window_size), None)
+                .await
+                .unwrap_err();
+            assert!(matches!(error, ClientError::InvalidParameter(_)));
+        }
+    }
 }
@@ -0,0 +1,823 @@
+use std::time::Duration;
+
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize};
+use serde_json::Value as JsonValue;
+use url::{form_urlencoded::Serializer, UrlQuery};
+
+use crate::{
+    http::client::{Client, ClientResult},
+    spot::{
+        CanceledOrder, OrderResponse, OrderResponseType, OrderSide, OrderType,
+        SelfTradePreventionMode, TimeInForce,
+    },
+    types::{Price, Quantity, Symbol, Timestamp},
+};
+
+impl Client {
+    pub async fn spot_oco_order(
+        &self,
+        order: &NewOcoOrder,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderList> {
+        self.place_order_list(
+            "/api/v3/orderList/oco",
+            |query_pairs| order.append_query_pairs(query_pairs),
+            2,
+            recv_window,
+        )
+        .await
+    }
+
+    pub async fn spot_oto_order(
+        &self,
+        order: &NewOtoOrder,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderList> {
+        self.place_order_list(
+            "/api/v3/orderList/oto",
+            |query_pairs| order.append_query_pairs(query_pairs),
+            2,
+            recv_window,
+        )
+        .await
+    }
+
+    pub async fn spot_otoco_order(
+        &self,
+        order: &NewOtocoOrder,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderList> {
+        self.place_order_list(
+            "/api/v3/orderList/otoco",
+            |query_pairs| order.append_query_pairs(query_pairs),
+            3,
+            recv_window,
+        )
+        .await
+    }
+
+    async fn place_order_list<F>(
+        &self,
+        path: &str,
+        append_query_pairs: F,
+        orders: u32,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderList>
+    where
+        F: FnOnce(&mut Serializer<'_, UrlQuery<'_>>),
+    {
+        let mut url = self.base_url()?;
+        url.set_path(path);
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            append_query_pairs(&mut query_pairs);
+        }
+
+        self.build_sign_request_post(url)?
+            .with_recv_window(recv_window)
+            .with_order_count(orders)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+
+    pub async fn spot_cancel_order_list(
+        &self,
+        symbol: &Symbol,
+        id: i64,
+        new_client_order_id: Option<&String>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderList> {
+        self.cancel_order_list(symbol, Some(id), None, new_client_order_id, recv_window)
+            .await
+    }
+
+    pub async fn spot_cancel_order_list_by_client_id(
+        &self,
+        symbol: &Symbol,
+        list_client_order_id: &String,
+        new_client_order_id: Option<&String>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderList> {
+        self.cancel_order_list(
+            symbol,
+            None,
+            Some(list_client_order_id),
+            new_client_order_id,
+            recv_window,
+        )
+        .await
+    }
+
+    async fn cancel_order_list(
+        &self,
+        symbol: &Symbol,
+        id: Option<i64>,
+        list_client_order_id: Option<&String>,
+        new_client_order_id: Option<&String>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderList> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/orderList");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+
+            if let Some(value) = id {
+                query_pairs.append_pair("orderListId", &value.to_string());
+            }
+
+            if let Some(value) = list_client_order_id {
+                query_pairs.append_pair("listClientOrderId", value);
+            }
+
+            if let Some(value) = new_client_order_id {
+                query_pairs.append_pair("newClientOrderId", value);
+            }
+        }
+
+        self.build_sign_request_delete(url)?
+            .with_recv_window(recv_window)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+
+    pub async fn spot_order_list_info(
+        &self,
+        id: i64,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderList> {
+        self.order_list_info(Some(id), None, recv_window).await
+    }
+
+    pub async fn spot_order_list_info_by_client_id(
+        &self,
+        orig_client_order_id: &String,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderList> {
+        self.order_list_info(None, Some(orig_client_order_id), recv_window)
+            .await
+    }
+
+    async fn order_list_info(
+        &self,
+        id: Option<i64>,
+        orig_client_order_id: Option<&String>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderList> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/orderList");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            if let Some(value) = id {
+                query_pairs.append_pair("orderListId", &value.to_string());
+            }
+
+            if let Some(value) = orig_client_order_id {
+                query_pairs.append_pair("origClientOrderId", value);
+            }
+        }
+
+        self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(4)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+
+    pub async fn spot_all_order_lists(
+        &self,
+        from_id: Option<i64>,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+        limit: Option<u16>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<Vec<OrderList>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/allOrderList");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            if let Some(value) = from_id {
+                query_pairs.append_pair("fromId", &value.to_string());
+            }
+
+            if let Some(value) = start_time {
+                query_pairs.append_pair("startTime", &value.to_string());
+            }
+
+            if let Some(value) = end_time {
+                query_pairs.append_pair("endTime", &value.to_string());
+            }
+
+            if let Some(value) = limit {
+                query_pairs.append_pair("limit", &value.to_string());
+            }
+        }
+
+        self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(20)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+
+    pub async fn spot_open_order_lists(
+        &self,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<Vec<OrderList>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/openOrderList");
+
+        self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(6)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+}
+
+// One order of a list, its parameters are sent with a prefix such as
+// `above`, `working` or `pendingBelow` depending on its role in the list
+#[derive(Debug, Clone)]
+pub struct OrderListLeg {
+    order_type: OrderType,
+    side: Option<OrderSide>,
+    quantity: Option<Quantity>,
+    price: Option<Price>,
+    stop_price: Option<Price>,
+    trailing_delta: Option<u32>,
+    iceberg_qty: Option<Quantity>,
+    time_in_force: Option<TimeInForce>,
+    client_order_id: Option<String>,
+    strategy_id: Option<i64>,
+    strategy_type: Option<i64>,
+}
+
+impl OrderListLeg {
+    pub fn new(order_type: OrderType) -> Self {
+        Self {
+            order_type,
+            side: None,
+            quantity: None,
+            price: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            time_in_force: None,
+            client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+        }
+    }
+
+    // Only used by the working and pending orders of an OTO list, the other
+    // lists take the side and quantity once for all their orders
+    pub fn set_side(mut self, value: OrderSide) -> Self {
+        self.side = Some(value);
+
+        self
+    }
+
+    pub fn set_quantity(mut self, value: Quantity) -> Self {
+        self.quantity = Some(value);
+
+        self
+    }
+
+    pub fn set_price(mut self, value: Price) -> Self {
+        self.price = Some(value);
+
+        self
+    }
+
+    pub fn set_stop_price(mut self, value: Price) -> Self {
+        self.stop_price = Some(value);
+
+        self
+    }
+
+    pub fn set_trailing_delta(mut self, value: u32) -> Self {
+        self.trailing_delta = Some(value);
+
+        self
+    }
+
+    pub fn set_iceberg_qty(mut self, value: Quantity) -> Self {
+        self.iceberg_qty = Some(value);
+
+        self
+    }
+
+    pub fn set_time_in_force(mut self, value: TimeInForce) -> Self {
+        self.time_in_force = Some(value);
+
+        self
+    }
+
+    pub fn set_client_order_id(mut self, value: String) -> Self {
+        self.client_order_id = Some(value);
+
+        self
+    }
+
+    pub fn set_strategy_id(mut self, value: i64) -> Self {
+        self.strategy_id = Some(value);
+
+        self
+    }
+
+    pub fn set_strategy_type(mut self, value: i64) -> Self {
+        self.strategy_type = Some(value);
+
+        self
+    }
+
+    fn append_query_pairs(&self, prefix: &str, query_pairs: &mut Serializer<'_, UrlQuery<'_>>) {
+        let key = |name: &str| format!("{}{}", prefix, name);
+
+        query_pairs.append_pair(&key("Type"), self.order_type.as_str());
+
+        if let Some(value) = self.side {
+            query_pairs.append_pair(&key("Side"), value.as_str());
+        }
+
+        if let Some(value) = &self.quantity {
+            query_pairs.append_pair(&key("Quantity"), &value.to_string());
+        }
+
+        if let Some(value) = &self.price {
+            query_pairs.append_pair(&key("Price"), &value.to_string());
+        }
+
+        if let Some(value) = &self.stop_price {
+            query_pairs.append_pair(&key("StopPrice"), &value.to_string());
+        }
+
+        if let Some(value) = self.trailing_delta {
+            query_pairs.append_pair(&key("TrailingDelta"), &value.to_string());
+        }
+
+        if let Some(value) = &self.iceberg_qty {
+            query_pairs.append_pair(&key("IcebergQty"), &value.to_string());
+        }
+
+        if let Some(value) = &self.time_in_force {
+            query_pairs.append_pair(&key("TimeInForce"), value.as_str());
+        }
+
+        if let Some(value) = &self.client_order_id {
+            query_pairs.append_pair(&key("ClientOrderId"), value);
+        }
+
+        if let Some(value) = self.strategy_id {
+            query_pairs.append_pair(&key("StrategyId"), &value.to_string());
+        }
+
+        if let Some(value) = self.strategy_type {
+            query_pairs.append_pair(&key("StrategyType"), &value.to_string());
+        }
+    }
+}
+
+// Parameters shared by every order list placement
+#[derive(Debug, Clone)]
+struct OrderListOptions {
+    symbol: Symbol,
+    list_client_order_id: Option<String>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    response_type: OrderResponseType,
+}
+
+impl OrderListOptions {
+    fn new(symbol: &Symbol) -> Self {
+        Self {
+            symbol: symbol.clone(),
+            list_client_order_id: None,
+            self_trade_prevention_mode: None,
+            response_type: OrderResponseType::Full,
+        }
+    }
+
+    fn append_query_pairs(&self, query_pairs: &mut Serializer<'_, UrlQuery<'_>>) {
+        query_pairs.append_pair("symbol", &self.symbol);
+
+        if let Some(value) = &self.list_client_order_id {
+            query_pairs.append_pair("listClientOrderId", value);
+        }
+
+        if let Some(value) = &self.self_trade_prevention_mode {
+            query_pairs.append_pair("selfTradePreventionMode", value.as_str());
+        }
+
+        query_pairs.append_pair("newOrderRespType", self.response_type.as_str());
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewOcoOrder {
+    options: OrderListOptions,
+    side: OrderSide,
+    quantity: Quantity,
+    above: OrderListLeg,
+    below: OrderListLeg,
+}
+
+impl NewOcoOrder {
+    pub fn new(
+        symbol: &Symbol,
+        side: OrderSide,
+        quantity: Quantity,
+        above: OrderListLeg,
+        below: OrderListLeg,
+    ) -> Self {
+        Self {
+            options: OrderListOptions::new(symbol),
+            side,
+            quantity,
+            above,
+            below,
+        }
+    }
+
+    pub fn set_list_client_order_id(mut self, value: String) -> Self {
+        self.options.list_client_order_id = Some(value);
+
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, value: SelfTradePreventionMode) -> Self {
+        self.options.self_trade_prevention_mode = Some(value);
+
+        self
+    }
+
+    pub fn set_response_type(mut self, value: OrderResponseType) -> Self {
+        self.options.response_type = value;
+
+        self
+    }
+
+    pub(crate) fn append_query_pairs(&self, query_pairs: &mut Serializer<'_, UrlQuery<'_>>) {
+        self.options.append_query_pairs(query_pairs);
+
+        query_pairs.append_pair("side", self.side.as_str());
+        query_pairs.append_pair("quantity", &self.quantity.to_string());
+
+        self.above.append_query_pairs("above", query_pairs);
+        self.below.append_query_pairs("below", query_pairs);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewOtoOrder {
+    options: OrderListOptions,
+    working: OrderListLeg,
+    pending: OrderListLeg,
+}
+
+impl NewOtoOrder {
+    pub fn new(symbol: &Symbol, working: OrderListLeg, pending: OrderListLeg) -> Self {
+        Self {
+            options: OrderListOptions::new(symbol),
+            working,
+            pending,
+        }
+    }
+
+    pub fn set_list_client_order_id(mut self, value: String) -> Self {
+        self.options.list_client_order_id = Some(value);
+
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, value: SelfTradePreventionMode) -> Self {
+        self.options.self_trade_prevention_mode = Some(value);
+
+        self
+    }
+
+    pub fn set_response_type(mut self, value: OrderResponseType) -> Self {
+        self.options.response_type = value;
+
+        self
+    }
+
+    pub(crate) fn append_query_pairs(&self, query_pairs: &mut Serializer<'_, UrlQuery<'_>>) {
+        self.options.append_query_pairs(query_pairs);
+
+        self.working.append_query_pairs("working", query_pairs);
+        self.pending.append_query_pairs("pending", query_pairs);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewOtocoOrder {
+    options: OrderListOptions,
+    working: OrderListLeg,
+    pending_side: OrderSide,
+    pending_quantity: Quantity,
+    pending_above: OrderListLeg,
+    pending_below: Option<OrderListLeg>,
+}
+
+impl NewOtocoOrder {
+    pub fn new(
+        symbol: &Symbol,
+        working: OrderListLeg,
+        pending_side: OrderSide,
+        pending_quantity: Quantity,
+        pending_above: OrderListLeg,
+    ) -> Self {
+        Self {
+            options: OrderListOptions::new(symbol),
+            working,
+            pending_side,
+            pending_quantity,
+            pending_above,
+            pending_below: None,
+        }
+    }
+
+    pub fn set_pending_below(mut self, value: OrderListLeg) -> Self {
+        self.pending_below = Some(value);
+
+        self
+    }
+
+    pub fn set_list_client_order_id(mut self, value: String) -> Self {
+        self.options.list_client_order_id = Some(value);
+
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, value: SelfTradePreventionMode) -> Self {
+        self.options.self_trade_prevention_mode = Some(value);
+
+        self
+    }
+
+    pub fn set_response_type(mut self, value: OrderResponseType) -> Self {
+        self.options.response_type = value;
+
+        self
+    }
+
+    pub(crate) fn append_query_pairs(&self, query_pairs: &mut Serializer<'_, UrlQuery<'_>>) {
+        self.options.append_query_pairs(query_pairs);
+
+        self.working.append_query_pairs("working", query_pairs);
+
+        query_pairs.append_pair("pendingSide", self.pending_side.as_str());
+        query_pairs.append_pair("pendingQuantity", &self.pending_quantity.to_string());
+
+        self.pending_above
+            .append_query_pairs("pendingAbove", query_pairs);
+
+        if let Some(value) = &self.pending_below {
+            value.append_query_pairs("pendingBelow", query_pairs);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContingencyType {
+    #[serde(rename = "OCO")]
+    Oco,
+
+    #[serde(rename = "OTO")]
+    Oto,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListStatusType {
+    #[serde(rename = "RESPONSE")]
+    Response,
+
+    #[serde(rename = "EXEC_STARTED")]
+    ExecStarted,
+
+    #[serde(rename = "UPDATED")]
+    Updated,
+
+    #[serde(rename = "ALL_DONE")]
+    AllDone,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListOrderStatus {
+    #[serde(rename = "EXECUTING")]
+    Executing,
+
+    #[serde(rename = "ALL_DONE")]
+    AllDone,
+
+    #[serde(rename = "REJECT")]
+    Reject,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderList {
+    #[serde(rename = "orderListId")]
+    pub order_list_id: i64,
+
+    #[serde(rename = "contingencyType")]
+    pub contingency_type: ContingencyType,
+
+    #[serde(rename = "listStatusType")]
+    pub list_status_type: ListStatusType,
+
+    #[serde(rename = "listOrderStatus")]
+    pub list_order_status: ListOrderStatus,
+
+    #[serde(rename = "listClientOrderId")]
+    pub list_client_order_id: String,
+
+    #[serde(rename = "transactionTime")]
+    pub transaction_time: Timestamp,
+
+    pub symbol: Symbol,
+
+    pub orders: Vec<OrderListOrder>,
+
+    // Only present when placing or cancelling a list
+    #[serde(
+        rename = "orderReports",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub order_reports: Vec<OrderListReport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderListOrder {
+    pub symbol: Symbol,
+
+    #[serde(rename = "orderId")]
+    pub order_id: i64,
+
+    #[serde(rename = "clientOrderId")]
+    pub client_order_id: String,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum OrderListReport {
+    Placed(OrderResponse),
+    Canceled(CanceledOrder),
+}
+
+impl<'de> Deserialize<'de> for OrderListReport {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = JsonValue::deserialize(deserializer)?;
+
+        let report = if value.get("origClientOrderId").is_some() {
+            Self::Canceled(serde_json::from_value(value).map_err(D::Error::custom)?)
+        } else {
+            Self::Placed(serde_json::from_value(value).map_err(D::Error::custom)?)
+        };
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NewOcoOrder, NewOtocoOrder, OrderList, OrderListLeg, OrderListReport};
+
+    use crate::http::client::tests::client_with_test_net_key_secret;
+    use crate::spot::{OrderResponse, OrderSide, OrderStatus, OrderType, TimeInForce};
+
+    #[tokio::test]
+    async fn test_spot_oco_order() {
+        let client = client_with_test_net_key_secret();
+        let order = NewOcoOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderSide::Sell,
+            "0.001".parse().unwrap(),
+            OrderListLeg::new(OrderType::LimitMaker).set_price("200000".parse().unwrap()),
+            OrderListLeg::new(OrderType::StopLoss).set_stop_price("10000".parse().unwrap()),
+        );
+
+        let list = client.spot_oco_order(&order, None).await.unwrap();
+        client
+            .spot_order_list_info(list.order_list_id, None)
+            .await
+            .unwrap();
+        client.spot_open_order_lists(None).await.unwrap();
+        client
+            .spot_cancel_order_list(&list.symbol, list.order_list_id, None, None)
+            .await
+            .unwrap();
+        client
+            .spot_all_order_lists(None, None, None, Some(10), None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_spot_otoco_order() {
+        let client = client_with_test_net_key_secret();
+        let order = NewOtocoOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderListLeg::new(OrderType::Limit)
+                .set_side(OrderSide::Buy)
+                .set_quantity("0.001".parse().unwrap())
+                .set_price("10000".parse().unwrap())
+                .set_time_in_force(TimeInForce::Gtc),
+            OrderSide::Sell,
+            "0.001".parse().unwrap(),
+            OrderListLeg::new(OrderType::LimitMaker).set_price("200000".parse().unwrap()),
+        )
+        .set_pending_below(
+            OrderListLeg::new(OrderType::StopLoss).set_stop_price("5000".parse().unwrap()),
+        );
+
+        let list = client.spot_otoco_order(&order, None).await.unwrap();
+        client
+            .spot_cancel_order_list_by_client_id(
+                &list.symbol,
+                &list.list_client_order_id,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_new_otoco_order_query_pairs() {
+        let order = NewOtocoOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderListLeg::new(OrderType::Limit)
+                .set_side(OrderSide::Buy)
+                .set_quantity("1".parse().unwrap())
+                .set_price("100".parse().unwrap())
+                .set_time_in_force(TimeInForce::Gtc),
+            OrderSide::Sell,
+            "1".parse().unwrap(),
+            OrderListLeg::new(OrderType::LimitMaker).set_price("110".parse().unwrap()),
+        )
+        .set_pending_below(
+            OrderListLeg::new(OrderType::StopLoss).set_stop_price("95".parse().unwrap()),
+        );
+
+        let mut url = url::Url::parse("https://api.binance.com/api/v3/orderList/otoco").unwrap();
+        order.append_query_pairs(&mut url.query_pairs_mut());
+
+        assert_eq!(
+            url.query(),
+            Some("symbol=BTCUSDT&newOrderRespType=FULL&workingType=LIMIT&workingSide=BUY&workingQuantity=1&workingPrice=100&workingTimeInForce=GTC&pendingSide=SELL&pendingQuantity=1&pendingAboveType=LIMIT_MAKER&pendingAbovePrice=110&pendingBelowType=STOP_LOSS&pendingBelowStopPrice=95")
+        );
+    }
+
+    #[test]
+    fn test_order_list_serde() {
+        let json_data = r#"{"orderListId":1,"contingencyType":"OCO","listStatusType":"ALL_DONE","listOrderStatus":"ALL_DONE","listClientOrderId":"C3wyj4WVEktd7u9aVBRXcN","transactionTime":1574040868128,"symbol":"LTCBTC","orders":[{"symbol":"LTCBTC","orderId":2,"clientOrderId":"pO9ufTiFGg3nw2fOdgeOXa"},{"symbol":"LTCBTC","orderId":3,"clientOrderId":"TXOvglzXuaubXAaENpaRCB"}],"orderReports":[{"symbol":"LTCBTC","origClientOrderId":"pO9ufTiFGg3nw2fOdgeOXa","orderId":2,"orderListId":0,"clientOrderId":"unfWT8ig8i0uj6lPuYLez6","transactTime":1688005070874,"price":"1.00000000","origQty":"10.00000000","executedQty":"0.00000000","origQuoteOrderQty":"0.000000","cummulativeQuoteQty":"0.00000000","status":"CANCELED","timeInForce":"GTC","type":"STOP_LOSS_LIMIT","side":"SELL","stopPrice":"1.00000000","selfTradePreventionMode":"NONE"}]}"#;
+
+        let list: OrderList = serde_json::from_str(json_data).unwrap();
+        assert_eq!(list.orders.len(), 2);
+        assert!(matches!(
+            list.order_reports[0],
+            OrderListReport::Canceled(_)
+        ));
+
+        let json_data = r#"{"orderListId":29,"contingencyType":"OCO","listStatusType":"EXEC_STARTED","listOrderStatus":"EXECUTING","listClientOrderId":"amEEAXryFzFwYF1FeRpUoZ","transactionTime":1565245913483,"symbol":"LTCBTC","orders":[{"symbol":"LTCBTC","orderId":4,"clientOrderId":"oD7aesZqjEGlZrbtRpy5zB"}]}"#;
+
+        let list: OrderList = serde_json::from_str(json_data).unwrap();
+        assert!(list.order_reports.is_empty());
+        assert_eq!(serde_json::to_string(&list).unwrap(), json_data);
+
+        let json_data = r#"{"orderListId":1,"contingencyType":"OTO","listStatusType":"EXEC_STARTED","listOrderStatus":"EXECUTING","listClientOrderId":"RumwQpBaDctlUu5jyG5rs0","transactionTime":1712291372842,"symbol":"ETHUSDT","orders":[{"symbol":"ETHUSDT","orderId":1,"clientOrderId":"A9hTtUjQvaoW2ZRqhPGnoG"},{"symbol":"ETHUSDT","orderId":2,"clientOrderId":"V1zFzPQkOH7aA3J2iUhKh8"}],"orderReports":[{"symbol":"ETHUSDT","orderId":1,"orderListId":1,"clientOrderId":"A9hTtUjQvaoW2ZRqhPGnoG","transactTime":1712291372842,"price":"3100.00000000","origQty":"1.00000000","executedQty":"0.00000000","origQuoteOrderQty":"0.00000000","cummulativeQuoteQty":"0.00000000","status":"NEW","timeInForce":"GTC","type":"LIMIT","side":"SELL","workingTime":1712291372842,"selfTradePreventionMode":"NONE"},{"symbol":"ETHUSDT","orderId":2,"orderListId":1,"clientOrderId":"V1zFzPQkOH7aA3J2iUhKh8","transactTime":1712291372842,"price":"3200.00000000","origQty":"1.00000000","executedQty":"0.00000000","origQuoteOrderQty":"0.00000000","cummulativeQuoteQty":"0.00000000","status":"PENDING_NEW","timeInForce":"GTC","type":"LIMIT","side":"SELL","workingTime":-1,"selfTradePreventionMode":"NONE"}]}"#;
+
+        let list: OrderList = serde_json::from_str(json_data).unwrap();
+        assert_eq!(list.order_reports.len(), 2);
+        match &list.order_reports[1] {
+            OrderListReport::Placed(OrderResponse::Result(order)) => {
+                assert_eq!(order.status, OrderStatus::PendingNew);
+            }
+            report => panic!("unexpected report {:?}", report),
+        }
+    }
+}
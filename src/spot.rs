@@ -1,19 +1,243 @@
-use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize};
+use serde_json::Value as JsonValue;
+use url::{form_urlencoded::Serializer, UrlQuery};
 
 use crate::{
-    http::client::{Client, ClientResult},
-    time::timestamp,
-    types::{Asset, Decimal, Price, Quantity, Symbol},
+    account::DiscountDetails,
+    http::{
+        client::{Client, ClientResult},
+        error::BinanceError,
+        limit::{RateLimitInterval, RateLimitType},
+        retry::new_client_order_id,
+    },
+    order_list::{ContingencyType, ListOrderStatus, OrderList, OrderListOrder},
+    types::{Asset, Commission, Decimal, Price, Quantity, Symbol, Timestamp},
 };
 
 impl Client {
+    pub async fn spot_order(
+        &self,
+        order: &NewOrder,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderResponse> {
+        self.place_order_response("/api/v3/order", order, recv_window)
+            .await
+    }
+
+    pub async fn spot_order_test(
+        &self,
+        order: &NewOrder,
+        compute_commission_rates: bool,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<TestOrderResponse> {
+        self.test_order(
+            "/api/v3/order/test",
+            order,
+            compute_commission_rates,
+            recv_window,
+        )
+        .await
+    }
+
+    // Smart Order Routing only accepts LIMIT and MARKET orders with a base
+    // quantity
+    pub async fn spot_sor_order(
+        &self,
+        order: &NewOrder,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderResponse> {
+        self.place_order_response("/api/v3/sor/order", order, recv_window)
+            .await
+    }
+
+    pub async fn spot_sor_order_test(
+        &self,
+        order: &NewOrder,
+        compute_commission_rates: bool,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<TestOrderResponse> {
+        self.test_order(
+            "/api/v3/sor/order/test",
+            order,
+            compute_commission_rates,
+            recv_window,
+        )
+        .await
+    }
+
+    async fn place_order_response(
+        &self,
+        path: &str,
+        order: &NewOrder,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderResponse> {
+        let response = match order.response_type {
+            OrderResponseType::Ack => {
+                OrderResponse::Ack(self.place_order(path, order, recv_window).await?)
+            }
+            OrderResponseType::Result => {
+                OrderResponse::Result(self.place_order(path, order, recv_window).await?)
+            }
+            OrderResponseType::Full => {
+                OrderResponse::Full(self.place_order(path, order, recv_window).await?)
+            }
+        };
+
+        Ok(response)
+    }
+
+    async fn test_order(
+        &self,
+        path: &str,
+        order: &NewOrder,
+        compute_commission_rates: bool,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<TestOrderResponse> {
+        let mut url = self.base_url()?;
+        url.set_path(path);
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            order.append_query_pairs(&mut query_pairs);
+
+            if compute_commission_rates {
+                query_pairs.append_pair("computeCommissionRates", "true");
+            }
+        }
+
+        let weight = match compute_commission_rates {
+            true => 20,
+            false => 1,
+        };
+
+        self.build_sign_request_post(url)?
+            .with_recv_window(recv_window)
+            .with_weight(weight)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+
     pub async fn spot_market_order_with_quote(
         &self,
         symbol: &Symbol,
         side: OrderSide,
         quote_quantity: &Quantity,
-        recv_window: Option<u8>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<OrderResponseFull> {
+        let order = NewOrder::new(symbol, side, OrderType::Market)
+            .set_quote_order_qty(quote_quantity.to_owned())
+            .set_response_type(OrderResponseType::Full);
+
+        self.place_order("/api/v3/order", &order, recv_window).await
+    }
+
+    pub async fn spot_market_order_with_base(
+        &self,
+        symbol: &Symbol,
+        side: OrderSide,
+        base_quantity: &Quantity,
+        recv_window: Option<Duration>,
     ) -> ClientResult<OrderResponseFull> {
+        let order = NewOrder::new(symbol, side, OrderType::Market)
+            .set_quantity(base_quantity.to_owned())
+            .set_response_type(OrderResponseType::Full);
+
+        self.place_order("/api/v3/order", &order, recv_window).await
+    }
+
+    async fn place_order<T>(
+        &self,
+        path: &str,
+        order: &NewOrder,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<T>
+    where
+        for<'a> T: Deserialize<'a>,
+    {
+        let mut url = self.base_url()?;
+        url.set_path(path);
+
+        // Retries have to resend the same id for Binance to reject duplicates
+        let client_order_id = match (&order.new_client_order_id, &self.retry_policy) {
+            (Some(value), _) => Some(value.clone()),
+            (None, Some(_)) => Some(new_client_order_id()?),
+            (None, None) => None,
+        };
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            order.append_query_pairs(&mut query_pairs);
+
+            if let (None, Some(value)) = (&order.new_client_order_id, &client_order_id) {
+                query_pairs.append_pair("newClientOrderId", value);
+            }
+        }
+
+        let mut request = self
+            .build_sign_request_post(url)?
+            .with_recv_window(recv_window)
+            .with_order_count(1)
+            .with_api_key(self.secret.api_key()?);
+
+        if let Some(value) = &client_order_id {
+            request = request.with_new_order(&order.symbol, value);
+        }
+
+        request.send().await
+    }
+
+    pub async fn spot_cancel_order(
+        &self,
+        symbol: &Symbol,
+        id: i64,
+        new_client_order_id: Option<&String>,
+        cancel_restrictions: Option<CancelRestrictions>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<CanceledOrder> {
+        self.cancel_order(
+            symbol,
+            Some(id),
+            None,
+            new_client_order_id,
+            cancel_restrictions,
+            recv_window,
+        )
+        .await
+    }
+
+    pub async fn spot_cancel_order_by_client_id(
+        &self,
+        symbol: &Symbol,
+        orig_client_order_id: &String,
+        new_client_order_id: Option<&String>,
+        cancel_restrictions: Option<CancelRestrictions>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<CanceledOrder> {
+        self.cancel_order(
+            symbol,
+            None,
+            Some(orig_client_order_id),
+            new_client_order_id,
+            cancel_restrictions,
+            recv_window,
+        )
+        .await
+    }
+
+    async fn cancel_order(
+        &self,
+        symbol: &Symbol,
+        id: Option<i64>,
+        orig_client_order_id: Option<&String>,
+        new_client_order_id: Option<&String>,
+        cancel_restrictions: Option<CancelRestrictions>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<CanceledOrder> {
         let mut url = self.base_url()?;
         url.set_path("/api/v3/order");
 
@@ -21,51 +245,147 @@ impl Client {
             let mut query_pairs = url.query_pairs_mut();
 
             query_pairs.append_pair("symbol", symbol);
-            query_pairs.append_pair("side", side.as_str());
-            query_pairs.append_pair("type", "MARKET");
-            query_pairs.append_pair("newOrderRespType", "FULL");
-            query_pairs.append_pair("quoteOrderQty", quote_quantity);
 
-            if let Some(value) = recv_window {
-                query_pairs.append_pair("recvWindow", &value.to_string());
+            if let Some(value) = id {
+                query_pairs.append_pair("orderId", &value.to_string());
+            }
+
+            if let Some(value) = orig_client_order_id {
+                query_pairs.append_pair("origClientOrderId", value);
+            }
+
+            if let Some(value) = new_client_order_id {
+                query_pairs.append_pair("newClientOrderId", value);
             }
 
-            query_pairs.append_pair("timestamp", &timestamp().as_millis().to_string());
+            if let Some(value) = cancel_restrictions {
+                query_pairs.append_pair("cancelRestrictions", value.as_str());
+            }
         }
 
-        self.build_sign_request_post(url)?
+        self.build_sign_request_delete(url)?
+            .with_recv_window(recv_window)
             .with_api_key(self.secret.api_key()?)
             .send()
             .await
     }
 
-    pub async fn spot_market_order_with_base(
+    pub async fn spot_cancel_open_orders(
         &self,
         symbol: &Symbol,
-        side: OrderSide,
-        base_quantity: &Quantity,
-        recv_window: Option<u8>,
-    ) -> ClientResult<OrderResponseFull> {
+        recv_window: Option<Duration>,
+    ) -> ClientResult<Vec<CanceledOpenOrder>> {
         let mut url = self.base_url()?;
-        url.set_path("/api/v3/order");
+        url.set_path("/api/v3/openOrders");
 
         {
             let mut query_pairs = url.query_pairs_mut();
 
             query_pairs.append_pair("symbol", symbol);
-            query_pairs.append_pair("side", side.as_str());
-            query_pairs.append_pair("type", "MARKET");
-            query_pairs.append_pair("newOrderRespType", "FULL");
-            query_pairs.append_pair("quantity", &base_quantity);
+        }
 
-            if let Some(value) = recv_window {
-                query_pairs.append_pair("recvWindow", &value.to_string());
-            }
+        self.build_sign_request_delete(url)?
+            .with_recv_window(recv_window)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+
+    pub async fn spot_cancel_replace(
+        &self,
+        order: &CancelReplaceOrder,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<CancelReplaceResponse> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/order/cancelReplace");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
 
-            query_pairs.append_pair("timestamp", &timestamp().as_millis().to_string());
+            order.append_query_pairs(&mut query_pairs);
         }
 
         self.build_sign_request_post(url)?
+            .with_recv_window(recv_window)
+            .with_order_count(1)
+            .with_api_key(self.secret.api_key()?)
+            .send_or_error_data()
+            .await
+    }
+
+    pub async fn spot_amend_order(
+        &self,
+        symbol: &Symbol,
+        id: i64,
+        new_qty: &Quantity,
+        new_client_order_id: Option<&String>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<AmendOrderResponse> {
+        self.amend_order(
+            symbol,
+            Some(id),
+            None,
+            new_qty,
+            new_client_order_id,
+            recv_window,
+        )
+        .await
+    }
+
+    pub async fn spot_amend_order_by_client_id(
+        &self,
+        symbol: &Symbol,
+        orig_client_order_id: &String,
+        new_qty: &Quantity,
+        new_client_order_id: Option<&String>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<AmendOrderResponse> {
+        self.amend_order(
+            symbol,
+            None,
+            Some(orig_client_order_id),
+            new_qty,
+            new_client_order_id,
+            recv_window,
+        )
+        .await
+    }
+
+    async fn amend_order(
+        &self,
+        symbol: &Symbol,
+        id: Option<i64>,
+        orig_client_order_id: Option<&String>,
+        new_qty: &Quantity,
+        new_client_order_id: Option<&String>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<AmendOrderResponse> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/order/amend/keepPriority");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+
+            if let Some(value) = id {
+                query_pairs.append_pair("orderId", &value.to_string());
+            }
+
+            if let Some(value) = orig_client_order_id {
+                query_pairs.append_pair("origClientOrderId", value);
+            }
+
+            if let Some(value) = new_client_order_id {
+                query_pairs.append_pair("newClientOrderId", value);
+            }
+
+            query_pairs.append_pair("newQty", &new_qty.to_string());
+        }
+
+        self.build_sign_request_put(url)?
+            .with_recv_window(recv_window)
+            .with_weight(4)
             .with_api_key(self.secret.api_key()?)
             .send()
             .await
@@ -75,7 +395,7 @@ impl Client {
         &self,
         symbol: &Symbol,
         id: i64,
-        recv_window: Option<u8>,
+        recv_window: Option<Duration>,
     ) -> ClientResult<OrderInfo> {
         let mut url = self.base_url()?;
         url.set_path("/api/v3/order");
@@ -83,16 +403,13 @@ impl Client {
         {
             let mut query_pairs = url.query_pairs_mut();
 
-            if let Some(value) = recv_window {
-                query_pairs.append_pair("recvWindow", &value.to_string());
-            }
-
             query_pairs.append_pair("symbol", symbol);
             query_pairs.append_pair("orderId", &id.to_string());
-            query_pairs.append_pair("timestamp", &timestamp().as_millis().to_string());
         }
 
         self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(4)
             .with_api_key(self.secret.api_key()?)
             .send()
             .await
@@ -102,10 +419,10 @@ impl Client {
         &self,
         symbol: &Symbol,
         id: Option<i64>,
-        start_time: Option<u128>,
-        end_time: Option<u128>,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
         limit: Option<u16>,
-        recv_window: Option<u8>,
+        recv_window: Option<Duration>,
     ) -> ClientResult<Vec<OrderInfo>> {
         let mut url = self.base_url()?;
         url.set_path("/api/v3/allOrders");
@@ -129,15 +446,154 @@ impl Client {
                 query_pairs.append_pair("limit", &value.to_string());
             }
 
-            if let Some(value) = recv_window {
-                query_pairs.append_pair("recvWindow", &value.to_string());
+            query_pairs.append_pair("symbol", symbol);
+        }
+
+        self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(20)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+
+    pub async fn spot_open_orders(
+        &self,
+        symbol: Option<&Symbol>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<Vec<OrderInfo>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/openOrders");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            if let Some(value) = symbol {
+                query_pairs.append_pair("symbol", value);
+            }
+        }
+
+        let weight = match symbol {
+            Some(_) => 6,
+            None => 80,
+        };
+
+        self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(weight)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+
+    pub async fn spot_order_count_usage(
+        &self,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<Vec<OrderCountUsage>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/rateLimit/order");
+
+        self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(40)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+
+    pub async fn spot_prevented_matches(
+        &self,
+        symbol: &Symbol,
+        query: PreventedMatchQuery,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<Vec<PreventedMatch>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/myPreventedMatches");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            query_pairs.append_pair("symbol", symbol);
+
+            match query {
+                PreventedMatchQuery::PreventedMatchId(value) => {
+                    query_pairs.append_pair("preventedMatchId", &value.to_string());
+                }
+                PreventedMatchQuery::OrderId(value) => {
+                    query_pairs.append_pair("orderId", &value.to_string());
+                }
+                PreventedMatchQuery::FromPreventedMatchId {
+                    order_id,
+                    from_prevented_match_id,
+                    limit,
+                } => {
+                    query_pairs.append_pair("orderId", &order_id.to_string());
+                    query_pairs
+                        .append_pair("fromPreventedMatchId", &from_prevented_match_id.to_string());
+
+                    if let Some(value) = limit {
+                        query_pairs.append_pair("limit", &value.to_string());
+                    }
+                }
             }
+        }
+
+        let weight = match query {
+            PreventedMatchQuery::PreventedMatchId(_) => 2,
+            _ => 20,
+        };
+
+        self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(weight)
+            .with_api_key(self.secret.api_key()?)
+            .send()
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn spot_allocations(
+        &self,
+        symbol: &Symbol,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+        from_allocation_id: Option<i64>,
+        limit: Option<u16>,
+        order_id: Option<i64>,
+        recv_window: Option<Duration>,
+    ) -> ClientResult<Vec<Allocation>> {
+        let mut url = self.base_url()?;
+        url.set_path("/api/v3/myAllocations");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
 
             query_pairs.append_pair("symbol", symbol);
-            query_pairs.append_pair("timestamp", &timestamp().as_millis().to_string());
+
+            if let Some(value) = start_time {
+                query_pairs.append_pair("startTime", &value.to_string());
+            }
+
+            if let Some(value) = end_time {
+                query_pairs.append_pair("endTime", &value.to_string());
+            }
+
+            if let Some(value) = from_allocation_id {
+                query_pairs.append_pair("fromAllocationId", &value.to_string());
+            }
+
+            if let Some(value) = limit {
+                query_pairs.append_pair("limit", &value.to_string());
+            }
+
+            if let Some(value) = order_id {
+                query_pairs.append_pair("orderId", &value.to_string());
+            }
         }
 
         self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(20)
             .with_api_key(self.secret.api_key()?)
             .send()
             .await
@@ -147,21 +603,22 @@ impl Client {
         &self,
         symbol: &Symbol,
         id: i64,
-        recv_window: Option<u8>,
+        recv_window: Option<Duration>,
     ) -> ClientResult<Vec<Trade>> {
         self.spot_trades(symbol, Some(id), None, None, None, None, recv_window)
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn spot_trades(
         &self,
         symbol: &Symbol,
         id: Option<i64>,
-        start_time: Option<u128>,
-        end_time: Option<u128>,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
         from_id: Option<i64>,
         limit: Option<u16>,
-        recv_window: Option<u8>,
+        recv_window: Option<Duration>,
     ) -> ClientResult<Vec<Trade>> {
         let mut url = self.base_url()?;
         url.set_path("/api/v3/myTrades");
@@ -189,77 +646,638 @@ impl Client {
                 query_pairs.append_pair("limit", &value.to_string());
             }
 
-            if let Some(value) = recv_window {
-                query_pairs.append_pair("recvWindow", &value.to_string());
-            }
-
             query_pairs.append_pair("symbol", symbol);
-            query_pairs.append_pair("timestamp", &timestamp().as_millis().to_string());
         }
 
+        let weight = match id {
+            Some(_) => 5,
+            None => 20,
+        };
+
         self.build_sign_request_get(url)?
+            .with_recv_window(recv_window)
+            .with_weight(weight)
             .with_api_key(self.secret.api_key()?)
             .send()
             .await
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OrderInfo {
-    pub symbol: Symbol,
+#[derive(Debug, Clone)]
+pub struct NewOrder {
+    symbol: Symbol,
+    side: OrderSide,
+    order_type: OrderType,
+    time_in_force: Option<TimeInForce>,
+    quantity: Option<Quantity>,
+    quote_order_qty: Option<Quantity>,
+    price: Option<Price>,
+    new_client_order_id: Option<String>,
+    strategy_id: Option<i64>,
+    strategy_type: Option<i64>,
+    stop_price: Option<Price>,
+    trailing_delta: Option<u32>,
+    iceberg_qty: Option<Quantity>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    response_type: OrderResponseType,
+}
 
-    #[serde(rename = "orderId")]
-    pub order_id: i64,
+impl NewOrder {
+    pub fn new(symbol: &Symbol, side: OrderSide, order_type: OrderType) -> Self {
+        Self {
+            symbol: symbol.clone(),
+            side,
+            order_type,
+            time_in_force: None,
+            quantity: None,
+            quote_order_qty: None,
+            price: None,
+            new_client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            self_trade_prevention_mode: None,
+            response_type: OrderResponseType::Full,
+        }
+    }
 
-    #[serde(rename = "orderListId")]
-    pub order_list_id: i64,
+    pub fn set_time_in_force(mut self, value: TimeInForce) -> Self {
+        self.time_in_force = Some(value);
 
-    #[serde(rename = "clientOrderId")]
-    pub client_order_id: String,
+        self
+    }
 
-    pub price: Price,
+    pub fn set_quantity(mut self, value: Quantity) -> Self {
+        self.quantity = Some(value);
 
-    #[serde(rename = "origQty")]
-    pub orig_qty: Quantity,
+        self
+    }
 
-    #[serde(rename = "executedQty")]
-    pub executed_qty: Quantity,
+    pub fn set_quote_order_qty(mut self, value: Quantity) -> Self {
+        self.quote_order_qty = Some(value);
 
-    #[serde(rename = "cummulativeQuoteQty")]
-    pub cummulative_quote_qty: Quantity,
+        self
+    }
 
-    pub status: OrderStatus,
+    pub fn set_price(mut self, value: Price) -> Self {
+        self.price = Some(value);
 
-    #[serde(rename = "timeInForce")]
-    pub time_in_force: String,
+        self
+    }
 
-    #[serde(rename = "type")]
-    pub order_type: OrderType,
+    pub fn set_new_client_order_id(mut self, value: String) -> Self {
+        self.new_client_order_id = Some(value);
 
-    pub side: OrderSide,
+        self
+    }
 
-    #[serde(rename = "stopPrice")]
-    pub stop_price: Price,
+    pub fn set_strategy_id(mut self, value: i64) -> Self {
+        self.strategy_id = Some(value);
 
-    #[serde(rename = "icebergQty")]
-    pub iceberg_qty: String,
+        self
+    }
 
-    pub time: u128,
+    // Binance rejects values below 1000000
+    pub fn set_strategy_type(mut self, value: i64) -> Self {
+        self.strategy_type = Some(value);
 
-    #[serde(rename = "updateTime")]
-    pub update_time: u128,
+        self
+    }
 
-    #[serde(rename = "isWorking")]
-    pub is_working: bool,
+    pub fn set_stop_price(mut self, value: Price) -> Self {
+        self.stop_price = Some(value);
+
+        self
+    }
+
+    pub fn set_trailing_delta(mut self, value: u32) -> Self {
+        self.trailing_delta = Some(value);
+
+        self
+    }
+
+    pub fn set_iceberg_qty(mut self, value: Quantity) -> Self {
+        self.iceberg_qty = Some(value);
+
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, value: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(value);
+
+        self
+    }
+
+    pub fn set_response_type(mut self, value: OrderResponseType) -> Self {
+        self.response_type = value;
+
+        self
+    }
+
+    pub fn response_type(&self) -> OrderResponseType {
+        self.response_type
+    }
+
+    pub(crate) fn append_query_pairs(&self, query_pairs: &mut Serializer<'_, UrlQuery<'_>>) {
+        query_pairs.append_pair("symbol", &self.symbol);
+        query_pairs.append_pair("side", self.side.as_str());
+        query_pairs.append_pair("type", self.order_type.as_str());
+
+        if let Some(value) = &self.time_in_force {
+            query_pairs.append_pair("timeInForce", value.as_str());
+        }
+
+        if let Some(value) = &self.quantity {
+            query_pairs.append_pair("quantity", &value.to_string());
+        }
+
+        if let Some(value) = &self.quote_order_qty {
+            query_pairs.append_pair("quoteOrderQty", &value.to_string());
+        }
+
+        if let Some(value) = &self.price {
+            query_pairs.append_pair("price", &value.to_string());
+        }
+
+        if let Some(value) = &self.new_client_order_id {
+            query_pairs.append_pair("newClientOrderId", value);
+        }
+
+        if let Some(value) = self.strategy_id {
+            query_pairs.append_pair("strategyId", &value.to_string());
+        }
+
+        if let Some(value) = self.strategy_type {
+            query_pairs.append_pair("strategyType", &value.to_string());
+        }
+
+        if let Some(value) = &self.stop_price {
+            query_pairs.append_pair("stopPrice", &value.to_string());
+        }
+
+        if let Some(value) = self.trailing_delta {
+            query_pairs.append_pair("trailingDelta", &value.to_string());
+        }
+
+        if let Some(value) = &self.iceberg_qty {
+            query_pairs.append_pair("icebergQty", &value.to_string());
+        }
+
+        if let Some(value) = &self.self_trade_prevention_mode {
+            query_pairs.append_pair("selfTradePreventionMode", value.as_str());
+        }
+
+        query_pairs.append_pair("newOrderRespType", self.response_type.as_str());
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderResponseType {
+    #[serde(rename = "ACK")]
+    Ack,
+
+    #[serde(rename = "RESULT")]
+    Result,
+
+    #[serde(rename = "FULL")]
+    Full,
+}
+
+impl OrderResponseType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Ack => "ACK",
+            Self::Result => "RESULT",
+            Self::Full => "FULL",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CancelReplaceOrder {
+    order: NewOrder,
+    mode: CancelReplaceMode,
+    cancel_order_id: Option<i64>,
+    cancel_orig_client_order_id: Option<String>,
+    cancel_new_client_order_id: Option<String>,
+    cancel_restrictions: Option<CancelRestrictions>,
+    order_rate_limit_exceeded_mode: Option<OrderRateLimitExceededMode>,
+}
+
+impl CancelReplaceOrder {
+    pub fn new(order: NewOrder, mode: CancelReplaceMode) -> Self {
+        Self {
+            order,
+            mode,
+            cancel_order_id: None,
+            cancel_orig_client_order_id: None,
+            cancel_new_client_order_id: None,
+            cancel_restrictions: None,
+            order_rate_limit_exceeded_mode: None,
+        }
+    }
+
+    pub fn set_cancel_order_id(mut self, value: i64) -> Self {
+        self.cancel_order_id = Some(value);
+
+        self
+    }
+
+    pub fn set_cancel_orig_client_order_id(mut self, value: String) -> Self {
+        self.cancel_orig_client_order_id = Some(value);
+
+        self
+    }
+
+    pub fn set_cancel_new_client_order_id(mut self, value: String) -> Self {
+        self.cancel_new_client_order_id = Some(value);
+
+        self
+    }
+
+    pub fn set_cancel_restrictions(mut self, value: CancelRestrictions) -> Self {
+        self.cancel_restrictions = Some(value);
+
+        self
+    }
+
+    pub fn set_order_rate_limit_exceeded_mode(mut self, value: OrderRateLimitExceededMode) -> Self {
+        self.order_rate_limit_exceeded_mode = Some(value);
+
+        self
+    }
+
+    pub(crate) fn append_query_pairs(&self, query_pairs: &mut Serializer<'_, UrlQuery<'_>>) {
+        self.order.append_query_pairs(query_pairs);
+
+        query_pairs.append_pair("cancelReplaceMode", self.mode.as_str());
+
+        if let Some(value) = self.cancel_order_id {
+            query_pairs.append_pair("cancelOrderId", &value.to_string());
+        }
+
+        if let Some(value) = &self.cancel_orig_client_order_id {
+            query_pairs.append_pair("cancelOrigClientOrderId", value);
+        }
+
+        if let Some(value) = &self.cancel_new_client_order_id {
+            query_pairs.append_pair("cancelNewClientOrderId", value);
+        }
+
+        if let Some(value) = self.cancel_restrictions {
+            query_pairs.append_pair("cancelRestrictions", value.as_str());
+        }
+
+        if let Some(value) = self.order_rate_limit_exceeded_mode {
+            query_pairs.append_pair("orderRateLimitExceededMode", value.as_str());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CancelReplaceMode {
+    #[serde(rename = "STOP_ON_FAILURE")]
+    StopOnFailure,
+
+    #[serde(rename = "ALLOW_FAILURE")]
+    AllowFailure,
+}
+
+impl CancelReplaceMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::StopOnFailure => "STOP_ON_FAILURE",
+            Self::AllowFailure => "ALLOW_FAILURE",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderRateLimitExceededMode {
+    #[serde(rename = "DO_NOTHING")]
+    DoNothing,
+
+    #[serde(rename = "CANCEL_ONLY")]
+    CancelOnly,
+}
+
+impl OrderRateLimitExceededMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::DoNothing => "DO_NOTHING",
+            Self::CancelOnly => "CANCEL_ONLY",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CancelReplaceResult {
+    #[serde(rename = "SUCCESS")]
+    Success,
+
+    #[serde(rename = "FAILURE")]
+    Failure,
+
+    #[serde(rename = "NOT_ATTEMPTED")]
+    NotAttempted,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+// A leg of a cancel-replace request either carries its response or the
+// error Binance reported for it
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum CancelReplaceLeg<T> {
+    Ok(T),
+    Err(BinanceError),
+}
+
+impl<'de, T> Deserialize<'de> for CancelReplaceLeg<T>
+where
+    for<'a> T: Deserialize<'a>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = JsonValue::deserialize(deserializer)?;
+
+        let leg = if value.get("code").is_some() {
+            Self::Err(serde_json::from_value(value).map_err(D::Error::custom)?)
+        } else {
+            Self::Ok(serde_json::from_value(value).map_err(D::Error::custom)?)
+        };
+
+        Ok(leg)
+    }
+}
+
+// Also returned when one or both legs failed, check `cancel_result` and
+// `new_order_result` before using the responses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelReplaceResponse {
+    #[serde(rename = "cancelResult")]
+    pub cancel_result: CancelReplaceResult,
+
+    #[serde(rename = "newOrderResult")]
+    pub new_order_result: CancelReplaceResult,
+
+    #[serde(rename = "cancelResponse")]
+    pub cancel_response: Option<CancelReplaceLeg<CanceledOrder>>,
+
+    #[serde(rename = "newOrderResponse")]
+    pub new_order_response: Option<CancelReplaceLeg<OrderResponse>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmendOrderResponse {
+    #[serde(rename = "transactTime")]
+    pub transact_time: Timestamp,
+
+    #[serde(rename = "executionId")]
+    pub execution_id: i64,
+
+    #[serde(rename = "amendedOrder")]
+    pub amended_order: AmendedOrder,
+
+    #[serde(rename = "listStatus", skip_serializing_if = "Option::is_none")]
+    pub list_status: Option<AmendedOrderList>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmendedOrder {
+    pub symbol: Symbol,
+
+    #[serde(rename = "orderId")]
+    pub order_id: i64,
+
+    #[serde(rename = "orderListId")]
+    pub order_list_id: i64,
+
+    #[serde(rename = "origClientOrderId")]
+    pub orig_client_order_id: String,
+
+    #[serde(rename = "clientOrderId")]
+    pub client_order_id: String,
+
+    pub price: Price,
+
+    pub qty: Quantity,
+
+    #[serde(rename = "executedQty")]
+    pub executed_qty: Quantity,
+
+    #[serde(rename = "preventedQty")]
+    pub prevented_qty: Quantity,
+
+    #[serde(rename = "quoteOrderQty")]
+    pub quote_order_qty: Quantity,
+
+    #[serde(rename = "cumulativeQuoteQty")]
+    pub cumulative_quote_qty: Quantity,
+
+    pub status: OrderStatus,
+
+    #[serde(rename = "timeInForce")]
+    pub time_in_force: TimeInForce,
+
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    pub side: OrderSide,
 
     #[serde(rename = "workingTime")]
-    pub working_time: u128,
+    pub working_time: Timestamp,
+
+    #[serde(rename = "selfTradePreventionMode")]
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+// List the amended order belongs to, a shorter form of `OrderList` without
+// the list status type and transaction time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmendedOrderList {
+    #[serde(rename = "orderListId")]
+    pub order_list_id: i64,
+
+    #[serde(rename = "contingencyType")]
+    pub contingency_type: ContingencyType,
+
+    #[serde(rename = "listOrderStatus")]
+    pub list_order_status: ListOrderStatus,
+
+    #[serde(rename = "listClientOrderId")]
+    pub list_client_order_id: String,
+
+    pub symbol: Symbol,
+
+    pub orders: Vec<OrderListOrder>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CancelRestrictions {
+    #[serde(rename = "ONLY_NEW")]
+    OnlyNew,
+
+    #[serde(rename = "ONLY_PARTIALLY_FILLED")]
+    OnlyPartiallyFilled,
+}
+
+impl CancelRestrictions {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::OnlyNew => "ONLY_NEW",
+            Self::OnlyPartiallyFilled => "ONLY_PARTIALLY_FILLED",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CanceledOrder {
+    pub symbol: Symbol,
+
+    #[serde(rename = "origClientOrderId")]
+    pub orig_client_order_id: String,
+
+    #[serde(rename = "orderId")]
+    pub order_id: i64,
+
+    #[serde(rename = "orderListId")]
+    pub order_list_id: i64,
+
+    #[serde(rename = "clientOrderId")]
+    pub client_order_id: String,
+
+    #[serde(rename = "transactTime")]
+    pub transact_time: Timestamp,
+
+    pub price: Price,
+
+    #[serde(rename = "origQty")]
+    pub orig_qty: Quantity,
+
+    #[serde(rename = "executedQty")]
+    pub executed_qty: Quantity,
+
+    #[serde(rename = "origQuoteOrderQty")]
+    pub orig_quote_order_qty: Quantity,
+
+    #[serde(rename = "cummulativeQuoteQty")]
+    pub cummulative_quote_qty: Quantity,
+
+    pub status: OrderStatus,
+
+    #[serde(rename = "timeInForce")]
+    pub time_in_force: TimeInForce,
+
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    pub side: OrderSide,
+
+    #[serde(rename = "selfTradePreventionMode")]
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+
+    #[serde(rename = "stopPrice", skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<Price>,
+
+    #[serde(rename = "icebergQty", skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<Quantity>,
+
+    #[serde(rename = "trailingDelta", skip_serializing_if = "Option::is_none")]
+    pub trailing_delta: Option<u32>,
+
+    #[serde(rename = "trailingTime", skip_serializing_if = "Option::is_none")]
+    pub trailing_time: Option<Timestamp>,
+
+    #[serde(rename = "strategyId", skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<i64>,
+
+    #[serde(rename = "strategyType", skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<i64>,
+}
+
+// Cancelling all open orders on a symbol also cancels its order lists,
+// which come back in the same array
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum CanceledOpenOrder {
+    Order(CanceledOrder),
+    OrderList(OrderList),
+}
+
+impl<'de> Deserialize<'de> for CanceledOpenOrder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = JsonValue::deserialize(deserializer)?;
+
+        let order = if value.get("orders").is_some() {
+            Self::OrderList(serde_json::from_value(value).map_err(D::Error::custom)?)
+        } else {
+            Self::Order(serde_json::from_value(value).map_err(D::Error::custom)?)
+        };
+
+        Ok(order)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderInfo {
+    pub symbol: Symbol,
+
+    #[serde(rename = "orderId")]
+    pub order_id: i64,
+
+    #[serde(rename = "orderListId")]
+    pub order_list_id: i64,
+
+    #[serde(rename = "clientOrderId")]
+    pub client_order_id: String,
+
+    pub price: Price,
+
+    #[serde(rename = "origQty")]
+    pub orig_qty: Quantity,
+
+    #[serde(rename = "executedQty")]
+    pub executed_qty: Quantity,
+
+    #[serde(rename = "cummulativeQuoteQty")]
+    pub cummulative_quote_qty: Quantity,
+
+    pub status: OrderStatus,
+
+    #[serde(rename = "timeInForce")]
+    pub time_in_force: TimeInForce,
+
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    pub side: OrderSide,
+
+    #[serde(rename = "stopPrice")]
+    pub stop_price: Price,
+
+    #[serde(rename = "icebergQty")]
+    pub iceberg_qty: Quantity,
+
+    pub time: Timestamp,
+
+    #[serde(rename = "updateTime")]
+    pub update_time: Timestamp,
+
+    #[serde(rename = "isWorking")]
+    pub is_working: bool,
+
+    #[serde(rename = "workingTime")]
+    pub working_time: Timestamp,
 
     #[serde(rename = "origQuoteOrderQty")]
     pub orig_quote_order_qty: Quantity,
 
     #[serde(rename = "selfTradePreventionMode")]
-    pub self_trade_prevention_mode: String,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -280,8 +1298,9 @@ impl OrderSide {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SelfTradePreventionMode {
+    #[serde(rename = "NONE")]
     None,
 
     #[serde(rename = "EXPIRE_TAKER")]
@@ -292,13 +1311,32 @@ pub enum SelfTradePreventionMode {
 
     #[serde(rename = "EXPIRE_BOTH")]
     ExpireBoth,
+
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+impl SelfTradePreventionMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::None => "NONE",
+            Self::ExpireTaker => "EXPIRE_TAKER",
+            Self::ExpireMaker => "EXPIRE_MAKER",
+            Self::ExpireBoth => "EXPIRE_BOTH",
+            Self::Unknown(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderStatus {
     #[serde(rename = "NEW")]
     New,
 
+    // Orders of a list waiting for the working order to be filled
+    #[serde(rename = "PENDING_NEW")]
+    PendingNew,
+
     #[serde(rename = "PARTIALLY_FILLED")]
     PartiallyFilled,
 
@@ -308,65 +1346,271 @@ pub enum OrderStatus {
     #[serde(rename = "CANCELED")]
     Canceled,
 
-    #[serde(rename = "PENDING_CANCEL")]
-    PendingCancel,
+    #[serde(rename = "PENDING_CANCEL")]
+    PendingCancel,
+
+    #[serde(rename = "REJECTED")]
+    Rejected,
+
+    #[serde(rename = "EXPIRED")]
+    Expired,
+
+    #[serde(rename = "EXPIRED_IN_MATCH")]
+    ExpiredInMatch,
+
+    // Added by Binance after this version, kept as sent
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeInForce {
+    #[serde(rename = "GTC")]
+    Gtc,
+
+    #[serde(rename = "IOC")]
+    Ioc,
+
+    #[serde(rename = "FOK")]
+    Fok,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl TimeInForce {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Gtc => "GTC",
+            Self::Ioc => "IOC",
+            Self::Fok => "FOK",
+            Self::Unknown(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderType {
+    #[serde(rename = "LIMIT")]
+    Limit,
+
+    #[serde(rename = "MARKET")]
+    Market,
+
+    #[serde(rename = "STOP_LOSS")]
+    StopLoss,
+
+    #[serde(rename = "STOP_LOSS_LIMIT")]
+    StopLossLimit,
+
+    #[serde(rename = "TAKE_PROFIT")]
+    TakeProfit,
+
+    #[serde(rename = "TAKE_PROFIT_LIMIT")]
+    TakeProfitLimit,
+
+    #[serde(rename = "LIMIT_MAKER")]
+    LimitMaker,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OrderType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Limit => "LIMIT",
+            Self::Market => "MARKET",
+            Self::StopLoss => "STOP_LOSS",
+            Self::StopLossLimit => "STOP_LOSS_LIMIT",
+            Self::TakeProfit => "TAKE_PROFIT",
+            Self::TakeProfitLimit => "TAKE_PROFIT_LIMIT",
+            Self::LimitMaker => "LIMIT_MAKER",
+            Self::Unknown(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderFill {
+    pub price: Price,
+
+    pub qty: Quantity,
+
+    pub commission: Quantity,
+
+    #[serde(rename = "commissionAsset")]
+    pub commission_asset: Asset,
+
+    #[serde(rename = "tradeId")]
+    pub trade_id: i64,
+
+    // Only present on fills of orders placed through SOR
+    #[serde(rename = "matchType", skip_serializing_if = "Option::is_none")]
+    pub match_type: Option<MatchType>,
+
+    #[serde(rename = "allocId", skip_serializing_if = "Option::is_none")]
+    pub alloc_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchType {
+    #[serde(rename = "ONE_PARTY_TRADE_REPORT")]
+    OnePartyTradeReport,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+// Where the order was matched, on the order book or through SOR
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkingFloor {
+    #[serde(rename = "EXCHANGE")]
+    Exchange,
+
+    #[serde(rename = "SOR")]
+    Sor,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum OrderResponse {
+    Full(OrderResponseFull),
+    Result(OrderResponseResult),
+    Ack(OrderResponseAck),
+}
+
+// Picked from the fields that only the richer response types carry rather
+// than by trying each variant in turn as untagged enums do
+impl<'de> Deserialize<'de> for OrderResponse {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = JsonValue::deserialize(deserializer)?;
+
+        let response = if value.get("fills").is_some() {
+            Self::Full(serde_json::from_value(value).map_err(D::Error::custom)?)
+        } else if value.get("status").is_some() {
+            Self::Result(serde_json::from_value(value).map_err(D::Error::custom)?)
+        } else {
+            Self::Ack(serde_json::from_value(value).map_err(D::Error::custom)?)
+        };
+
+        Ok(response)
+    }
+}
+
+impl OrderResponse {
+    pub fn symbol(&self) -> &Symbol {
+        match self {
+            Self::Full(v) => &v.symbol,
+            Self::Result(v) => &v.symbol,
+            Self::Ack(v) => &v.symbol,
+        }
+    }
+
+    pub fn order_id(&self) -> i64 {
+        match self {
+            Self::Full(v) => v.order_id,
+            Self::Result(v) => v.order_id,
+            Self::Ack(v) => v.order_id,
+        }
+    }
+
+    pub fn client_order_id(&self) -> &String {
+        match self {
+            Self::Full(v) => &v.client_order_id,
+            Self::Result(v) => &v.client_order_id,
+            Self::Ack(v) => &v.client_order_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderResponseAck {
+    pub symbol: Symbol,
+
+    #[serde(rename = "orderId")]
+    pub order_id: i64,
+
+    #[serde(rename = "orderListId")]
+    pub order_list_id: i64,
+
+    #[serde(rename = "clientOrderId")]
+    pub client_order_id: String,
+
+    #[serde(rename = "transactTime")]
+    pub transact_time: Timestamp,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderResponseResult {
+    pub symbol: Symbol,
+
+    #[serde(rename = "orderId")]
+    pub order_id: i64,
+
+    #[serde(rename = "orderListId")]
+    pub order_list_id: i64,
+
+    #[serde(rename = "clientOrderId")]
+    pub client_order_id: String,
+
+    #[serde(rename = "transactTime")]
+    pub transact_time: Timestamp,
+
+    pub price: Price,
 
-    #[serde(rename = "REJECTED")]
-    Rejected,
+    #[serde(rename = "origQty")]
+    pub orig_qty: Quantity,
 
-    #[serde(rename = "EXPIRED")]
-    Expired,
+    #[serde(rename = "executedQty")]
+    pub executed_qty: Quantity,
 
-    #[serde(rename = "EXPIRED_IN_MATCH")]
-    ExpiredInMatch,
-}
+    #[serde(rename = "cummulativeQuoteQty")]
+    pub cummulative_quote_qty: Quantity,
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub enum TimeInForce {
-    #[serde(rename = "GTC")]
-    Gtc,
+    pub status: OrderStatus,
 
-    #[serde(rename = "IOC")]
-    Ioc,
+    #[serde(rename = "timeInForce")]
+    pub time_in_force: TimeInForce,
 
-    #[serde(rename = "FOK")]
-    Fok,
-}
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub enum OrderType {
-    #[serde(rename = "LIMIT")]
-    Limit,
+    pub side: OrderSide,
 
-    #[serde(rename = "MARKET")]
-    Market,
+    #[serde(rename = "workingTime")]
+    pub working_time: Timestamp,
 
-    #[serde(rename = "STOP_LOSS")]
-    StopLoss,
+    #[serde(rename = "selfTradePreventionMode")]
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
 
-    #[serde(rename = "STOP_LOSS_LIMIT")]
-    StopLossLimit,
+    #[serde(rename = "stopPrice", skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<Price>,
 
-    #[serde(rename = "TAKE_PROFIT")]
-    TakeProfit,
+    #[serde(rename = "icebergQty", skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<Quantity>,
 
-    #[serde(rename = "TAKE_PROFIT_LIMIT")]
-    TakeProfitLimit,
-}
+    #[serde(rename = "trailingDelta", skip_serializing_if = "Option::is_none")]
+    pub trailing_delta: Option<u32>,
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OrderFill {
-    pub price: Price,
+    #[serde(rename = "trailingTime", skip_serializing_if = "Option::is_none")]
+    pub trailing_time: Option<Timestamp>,
 
-    pub qty: Quantity,
+    #[serde(rename = "strategyId", skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<i64>,
 
-    pub commission: Quantity,
+    #[serde(rename = "strategyType", skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<i64>,
 
-    #[serde(rename = "commissionAsset")]
-    pub commission_asset: Symbol,
+    #[serde(rename = "workingFloor", skip_serializing_if = "Option::is_none")]
+    pub working_floor: Option<WorkingFloor>,
 
-    #[serde(rename = "tradeId")]
-    pub trade_id: i64,
+    #[serde(rename = "usedSor", skip_serializing_if = "Option::is_none")]
+    pub used_sor: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -383,7 +1627,7 @@ pub struct OrderResponseFull {
     pub client_order_id: String,
 
     #[serde(rename = "transactTime")]
-    pub transact_time: u128,
+    pub transact_time: Timestamp,
 
     pub price: Price,
 
@@ -407,14 +1651,169 @@ pub struct OrderResponseFull {
     pub side: OrderSide,
 
     #[serde(rename = "workingTime")]
-    pub working_time: u128,
+    pub working_time: Timestamp,
 
     #[serde(rename = "selfTradePreventionMode")]
     pub self_trade_prevention_mode: SelfTradePreventionMode,
 
+    #[serde(rename = "stopPrice", skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<Price>,
+
+    #[serde(rename = "icebergQty", skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<Quantity>,
+
+    #[serde(rename = "trailingDelta", skip_serializing_if = "Option::is_none")]
+    pub trailing_delta: Option<u32>,
+
+    #[serde(rename = "trailingTime", skip_serializing_if = "Option::is_none")]
+    pub trailing_time: Option<Timestamp>,
+
+    #[serde(rename = "strategyId", skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<i64>,
+
+    #[serde(rename = "strategyType", skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<i64>,
+
+    #[serde(rename = "workingFloor", skip_serializing_if = "Option::is_none")]
+    pub working_floor: Option<WorkingFloor>,
+
+    #[serde(rename = "usedSor", skip_serializing_if = "Option::is_none")]
+    pub used_sor: Option<bool>,
+
     pub fills: Vec<OrderFill>,
 }
 
+// Empty unless commission rates were requested
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestOrderResponse {
+    #[serde(
+        rename = "standardCommissionForOrder",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub standard_commission_for_order: Option<OrderCommissionRates>,
+
+    #[serde(
+        rename = "taxCommissionForOrder",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tax_commission_for_order: Option<OrderCommissionRates>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<DiscountDetails>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderCommissionRates {
+    pub maker: Commission,
+    pub taker: Commission,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreventedMatchQuery {
+    PreventedMatchId(i64),
+    OrderId(i64),
+    FromPreventedMatchId {
+        order_id: i64,
+        from_prevented_match_id: i64,
+        limit: Option<u16>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreventedMatch {
+    pub symbol: Symbol,
+
+    #[serde(rename = "preventedMatchId")]
+    pub prevented_match_id: i64,
+
+    #[serde(rename = "takerOrderId")]
+    pub taker_order_id: i64,
+
+    #[serde(rename = "makerSymbol")]
+    pub maker_symbol: Symbol,
+
+    #[serde(rename = "makerOrderId")]
+    pub maker_order_id: i64,
+
+    #[serde(rename = "tradeGroupId")]
+    pub trade_group_id: i64,
+
+    #[serde(rename = "selfTradePreventionMode")]
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+
+    pub price: Price,
+
+    #[serde(rename = "makerPreventedQuantity")]
+    pub maker_prevented_quantity: Quantity,
+
+    #[serde(rename = "transactTime")]
+    pub transact_time: Timestamp,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Allocation {
+    pub symbol: Symbol,
+
+    #[serde(rename = "allocationId")]
+    pub allocation_id: i64,
+
+    #[serde(rename = "allocationType")]
+    pub allocation_type: AllocationType,
+
+    #[serde(rename = "orderId")]
+    pub order_id: i64,
+
+    #[serde(rename = "orderListId")]
+    pub order_list_id: i64,
+
+    pub price: Price,
+
+    pub qty: Quantity,
+
+    #[serde(rename = "quoteQty")]
+    pub quote_qty: Quantity,
+
+    pub commission: Decimal,
+
+    #[serde(rename = "commissionAsset")]
+    pub commission_asset: Asset,
+
+    pub time: Timestamp,
+
+    #[serde(rename = "isBuyer")]
+    pub is_buyer: bool,
+
+    #[serde(rename = "isMaker")]
+    pub is_maker: bool,
+
+    #[serde(rename = "isAllocator")]
+    pub is_allocator: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AllocationType {
+    #[serde(rename = "SOR")]
+    Sor,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderCountUsage {
+    #[serde(rename = "rateLimitType")]
+    pub rate_limit_type: RateLimitType,
+
+    pub interval: RateLimitInterval,
+
+    #[serde(rename = "intervalNum")]
+    pub interval_num: u16,
+
+    pub limit: u32,
+
+    pub count: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Trade {
     pub symbol: Symbol,
@@ -422,7 +1821,7 @@ pub struct Trade {
     pub price: Price,
     pub qty: Quantity,
     pub commission: Decimal,
-    pub time: u128,
+    pub time: Timestamp,
 
     #[serde(rename = "orderId")]
     pub order_id: u64,
@@ -448,7 +1847,11 @@ pub struct Trade {
 
 #[cfg(test)]
 mod tests {
-    use super::OrderSide;
+    use super::{
+        AmendOrderResponse, CancelReplaceLeg, CancelReplaceMode, CancelReplaceOrder,
+        CancelReplaceResponse, CancelReplaceResult, CancelRestrictions, MatchType, NewOrder,
+        OrderResponse, OrderResponseType, OrderSide, OrderType, TimeInForce, WorkingFloor,
+    };
 
     use crate::http::client::tests::client_with_test_net_key_secret;
 
@@ -457,9 +1860,9 @@ mod tests {
         let client = client_with_test_net_key_secret();
         client
             .spot_market_order_with_quote(
-                &"BTCUSDT".into(),
+                &"BTCUSDT".parse().unwrap(),
                 OrderSide::Buy,
-                &"10.14159".into(),
+                &"10.14159".parse().unwrap(),
                 None,
             )
             .await
@@ -467,9 +1870,9 @@ mod tests {
 
         client
             .spot_market_order_with_quote(
-                &"BTCUSDT".into(),
+                &"BTCUSDT".parse().unwrap(),
                 OrderSide::Sell,
-                &"10.14159".into(),
+                &"10.14159".parse().unwrap(),
                 None,
             )
             .await
@@ -480,24 +1883,343 @@ mod tests {
     async fn test_spot_market_order_with_base() {
         let client = client_with_test_net_key_secret();
         client
-            .spot_market_order_with_base(&"BTCUSDT".into(), OrderSide::Buy, &"0.0001".into(), None)
+            .spot_market_order_with_base(
+                &"BTCUSDT".parse().unwrap(),
+                OrderSide::Buy,
+                &"0.0001".parse().unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        client
+            .spot_market_order_with_base(
+                &"BTCUSDT".parse().unwrap(),
+                OrderSide::Sell,
+                &"0.0001".parse().unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_spot_order() {
+        let client = client_with_test_net_key_secret();
+        let order = NewOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderSide::Buy,
+            OrderType::Limit,
+        )
+        .set_time_in_force(TimeInForce::Gtc)
+        .set_quantity("0.001".parse().unwrap())
+        .set_price("10000".parse().unwrap())
+        .set_response_type(OrderResponseType::Result);
+
+        let response = client.spot_order(&order, None).await.unwrap();
+        assert!(matches!(response, OrderResponse::Result(_)));
+    }
+
+    #[tokio::test]
+    async fn test_spot_order_test() {
+        let client = client_with_test_net_key_secret();
+        let order = NewOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderSide::Buy,
+            OrderType::Limit,
+        )
+        .set_time_in_force(TimeInForce::Gtc)
+        .set_quantity("0.001".parse().unwrap())
+        .set_price("10000".parse().unwrap());
+
+        let response = client.spot_order_test(&order, false, None).await.unwrap();
+        assert!(response.standard_commission_for_order.is_none());
+
+        let response = client.spot_order_test(&order, true, None).await.unwrap();
+        assert!(response.standard_commission_for_order.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_spot_sor_order() {
+        let client = client_with_test_net_key_secret();
+        let order = NewOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderSide::Buy,
+            OrderType::Market,
+        )
+        .set_quantity("0.001".parse().unwrap());
+
+        client
+            .spot_sor_order_test(&order, true, None)
+            .await
+            .unwrap();
+
+        let response = client.spot_sor_order(&order, None).await.unwrap();
+        client
+            .spot_allocations(
+                response.symbol(),
+                None,
+                None,
+                None,
+                None,
+                Some(response.order_id()),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_spot_prevented_matches() {
+        use super::PreventedMatchQuery;
+
+        let client = client_with_test_net_key_secret();
+        let order = NewOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderSide::Buy,
+            OrderType::Limit,
+        )
+        .set_time_in_force(TimeInForce::Gtc)
+        .set_quantity("0.001".parse().unwrap())
+        .set_price("10000".parse().unwrap())
+        .set_response_type(OrderResponseType::Ack);
+
+        let response = client.spot_order(&order, None).await.unwrap();
+        client
+            .spot_prevented_matches(
+                response.symbol(),
+                PreventedMatchQuery::OrderId(response.order_id()),
+                None,
+            )
+            .await
+            .unwrap();
+        client
+            .spot_prevented_matches(
+                response.symbol(),
+                PreventedMatchQuery::FromPreventedMatchId {
+                    order_id: response.order_id(),
+                    from_prevented_match_id: 0,
+                    limit: Some(10),
+                },
+                None,
+            )
+            .await
+            .unwrap();
+
+        client
+            .spot_cancel_open_orders(&"BTCUSDT".parse().unwrap(), None)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sor_order_response_serde() {
+        let json_data = r#"{"symbol":"BTCUSDT","orderId":2,"orderListId":-1,"clientOrderId":"sBI1KM6nNtOfj5tccZSKly","transactTime":1689149087774,"price":"31000.00000000","origQty":"0.50000000","executedQty":"0.50000000","cummulativeQuoteQty":"14000.00000000","status":"FILLED","timeInForce":"GTC","type":"LIMIT","side":"BUY","workingTime":1689149087774,"selfTradePreventionMode":"NONE","workingFloor":"SOR","usedSor":true,"fills":[{"price":"28000.00000000","qty":"0.50000000","commission":"0.00000000","commissionAsset":"BTC","tradeId":-1,"matchType":"ONE_PARTY_TRADE_REPORT","allocId":0}]}"#;
+
+        let response: OrderResponse = serde_json::from_str(json_data).unwrap();
+        match &response {
+            OrderResponse::Full(v) => {
+                assert_eq!(v.used_sor, Some(true));
+                assert_eq!(v.working_floor, Some(WorkingFloor::Sor));
+                assert_eq!(v.fills[0].alloc_id, Some(0));
+                assert_eq!(v.fills[0].match_type, Some(MatchType::OnePartyTradeReport));
+            }
+            _ => panic!("expected a FULL response"),
+        }
+
+        assert_eq!(serde_json::to_string(&response).unwrap(), json_data);
+    }
+
+    #[test]
+    fn test_test_order_response_serde() {
+        use super::TestOrderResponse;
+
+        let json_data = r#"{"standardCommissionForOrder":{"maker":"0.00000112","taker":"0.00000114"},"taxCommissionForOrder":{"maker":"0.00000112","taker":"0.00000114"},"discount":{"enabledForAccount":true,"enabledForSymbol":true,"discountAsset":"BNB","discount":"0.25000000"}}"#;
+
+        let response: TestOrderResponse = serde_json::from_str(json_data).unwrap();
+        assert_eq!(
+            response
+                .standard_commission_for_order
+                .as_ref()
+                .unwrap()
+                .taker
+                .to_string(),
+            "0.00000114"
+        );
+        assert_eq!(serde_json::to_string(&response).unwrap(), json_data);
+
+        let response: TestOrderResponse = serde_json::from_str("{}").unwrap();
+        assert!(response.discount.is_none());
+    }
+
+    #[test]
+    fn test_new_order_query_pairs() {
+        use super::SelfTradePreventionMode;
+
+        let order = NewOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderSide::Sell,
+            OrderType::StopLossLimit,
+        )
+        .set_time_in_force(TimeInForce::Gtc)
+        .set_quantity("0.5".parse().unwrap())
+        .set_price("60000".parse().unwrap())
+        .set_stop_price("61000".parse().unwrap())
+        .set_new_client_order_id("my-order-1".into())
+        .set_self_trade_prevention_mode(SelfTradePreventionMode::ExpireMaker)
+        .set_response_type(OrderResponseType::Ack);
+
+        let mut url = url::Url::parse("https://api.binance.com/api/v3/order").unwrap();
+        order.append_query_pairs(&mut url.query_pairs_mut());
+
+        assert_eq!(
+            url.query(),
+            Some("symbol=BTCUSDT&side=SELL&type=STOP_LOSS_LIMIT&timeInForce=GTC&quantity=0.5&price=60000&newClientOrderId=my-order-1&stopPrice=61000&selfTradePreventionMode=EXPIRE_MAKER&newOrderRespType=ACK")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spot_cancel_order() {
+        let client = client_with_test_net_key_secret();
+        let order = NewOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderSide::Buy,
+            OrderType::Limit,
+        )
+        .set_time_in_force(TimeInForce::Gtc)
+        .set_quantity("0.001".parse().unwrap())
+        .set_price("10000".parse().unwrap())
+        .set_response_type(OrderResponseType::Ack);
+
+        let response = client.spot_order(&order, None).await.unwrap();
+        client
+            .spot_cancel_order(response.symbol(), response.order_id(), None, None, None)
+            .await
+            .unwrap();
+
+        let response = client.spot_order(&order, None).await.unwrap();
+        client
+            .spot_cancel_order_by_client_id(
+                response.symbol(),
+                response.client_order_id(),
+                None,
+                Some(CancelRestrictions::OnlyNew),
+                None,
+            )
+            .await
+            .unwrap();
+
+        client.spot_order(&order, None).await.unwrap();
+        client
+            .spot_cancel_open_orders(&"BTCUSDT".parse().unwrap(), None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_spot_cancel_replace() {
+        let client = client_with_test_net_key_secret();
+        let order = NewOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderSide::Buy,
+            OrderType::Limit,
+        )
+        .set_time_in_force(TimeInForce::Gtc)
+        .set_quantity("0.001".parse().unwrap())
+        .set_price("10000".parse().unwrap())
+        .set_response_type(OrderResponseType::Ack);
+
+        let response = client.spot_order(&order, None).await.unwrap();
+        let replace = CancelReplaceOrder::new(
+            order.set_price("10001".parse().unwrap()),
+            CancelReplaceMode::StopOnFailure,
+        )
+        .set_cancel_order_id(response.order_id());
+
+        let response = client.spot_cancel_replace(&replace, None).await.unwrap();
+        assert_eq!(response.cancel_result, CancelReplaceResult::Success);
+        assert_eq!(response.new_order_result, CancelReplaceResult::Success);
+
+        client
+            .spot_cancel_open_orders(&"BTCUSDT".parse().unwrap(), None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_spot_amend_order() {
+        let client = client_with_test_net_key_secret();
+        let order = NewOrder::new(
+            &"BTCUSDT".parse().unwrap(),
+            OrderSide::Buy,
+            OrderType::Limit,
+        )
+        .set_time_in_force(TimeInForce::Gtc)
+        .set_quantity("0.002".parse().unwrap())
+        .set_price("10000".parse().unwrap())
+        .set_response_type(OrderResponseType::Ack);
+
+        let response = client.spot_order(&order, None).await.unwrap();
+        client
+            .spot_amend_order(
+                response.symbol(),
+                response.order_id(),
+                &"0.001".parse().unwrap(),
+                None,
+                None,
+            )
             .await
             .unwrap();
 
         client
-            .spot_market_order_with_base(&"BTCUSDT".into(), OrderSide::Sell, &"0.0001".into(), None)
+            .spot_cancel_open_orders(&"BTCUSDT".parse().unwrap(), None)
             .await
             .unwrap();
     }
 
+    #[test]
+    fn test_cancel_replace_partial_failure_serde() {
+        let json_data = r#"{"cancelResult":"SUCCESS","newOrderResult":"FAILURE","cancelResponse":{"symbol":"BTCUSDT","origClientOrderId":"DnLo3vTAQcjha43lAZhZ0y","orderId":9,"orderListId":-1,"clientOrderId":"osxN3JXAtJvKvCqGeMWMVR","transactTime":1684804350068,"price":"0.01000000","origQty":"0.000100","executedQty":"0.00000000","origQuoteOrderQty":"0.000000","cummulativeQuoteQty":"0.00000000","status":"CANCELED","timeInForce":"GTC","type":"LIMIT","side":"SELL","selfTradePreventionMode":"NONE"},"newOrderResponse":{"code":-2010,"msg":"Order would immediately match and take."}}"#;
+
+        let response: CancelReplaceResponse = serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.cancel_result, CancelReplaceResult::Success);
+        assert_eq!(response.new_order_result, CancelReplaceResult::Failure);
+        assert!(matches!(
+            response.cancel_response,
+            Some(CancelReplaceLeg::Ok(_))
+        ));
+        assert!(matches!(
+            response.new_order_response,
+            Some(CancelReplaceLeg::Err(_))
+        ));
+
+        let json_data = r#"{"cancelResult":"FAILURE","newOrderResult":"NOT_ATTEMPTED","cancelResponse":{"code":-2011,"msg":"Unknown order sent."},"newOrderResponse":null}"#;
+
+        let response: CancelReplaceResponse = serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.new_order_result, CancelReplaceResult::NotAttempted);
+        assert!(response.new_order_response.is_none());
+    }
+
+    #[test]
+    fn test_amend_order_response_serde() {
+        let json_data = r#"{"transactTime":1741669661670,"executionId":22,"amendedOrder":{"symbol":"BTCUSDT","orderId":9,"orderListId":1,"origClientOrderId":"W0fJ9fiLKHOJutovPK3oJp","clientOrderId":"UQ1Np3bmQ71jJzsSDW9Vpi","price":"0.00000000","qty":"4.00000000","executedQty":"0.00000000","preventedQty":"0.00000000","quoteOrderQty":"0.00000000","cumulativeQuoteQty":"0.00000000","status":"PENDING_NEW","timeInForce":"GTC","type":"MARKET","side":"BUY","workingTime":-1,"selfTradePreventionMode":"NONE"},"listStatus":{"orderListId":1,"contingencyType":"OTO","listOrderStatus":"EXECUTING","listClientOrderId":"AT7FTxZXylVSwRoZs52mt3","symbol":"BTCUSDT","orders":[{"symbol":"BTCUSDT","orderId":8,"clientOrderId":"GkwwHZUUbFtZOoH1YsZk9Q"},{"symbol":"BTCUSDT","orderId":9,"clientOrderId":"UQ1Np3bmQ71jJzsSDW9Vpi"}]}}"#;
+
+        let response: AmendOrderResponse = serde_json::from_str(json_data).unwrap();
+        assert_eq!(response.amended_order.order_list_id, 1);
+        let list_status = response.list_status.as_ref().unwrap();
+        assert_eq!(list_status.orders.len(), 2);
+        assert_eq!(list_status.orders[1].order_id, 9);
+        assert_eq!(serde_json::to_string(&response).unwrap(), json_data);
+    }
+
     #[tokio::test]
     async fn test_spot_order_info() {
         let client = client_with_test_net_key_secret();
         let order = client
             .spot_market_order_with_quote(
-                &"BTCUSDT".into(),
+                &"BTCUSDT".parse().unwrap(),
                 OrderSide::Buy,
-                &"10.14159".into(),
+                &"10.14159".parse().unwrap(),
                 None,
             )
             .await
@@ -514,25 +2236,49 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_spot_open_orders() {
+        let client = client_with_test_net_key_secret();
+        client
+            .spot_open_orders(Some(&"BTCUSDT".parse().unwrap()), None)
+            .await
+            .unwrap();
+        client.spot_open_orders(None, None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_spot_order_count_usage() {
+        let client = client_with_test_net_key_secret();
+        client.spot_order_count_usage(None).await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_spot_trade() {
         let client = client_with_test_net_key_secret();
         let order = client
             .spot_market_order_with_quote(
-                &"BTCUSDT".into(),
+                &"BTCUSDT".parse().unwrap(),
                 OrderSide::Buy,
-                &"10.14159".into(),
+                &"10.14159".parse().unwrap(),
                 None,
             )
             .await
             .unwrap();
 
         client
-            .spot_trade(&"BTCUSDT".into(), order.order_id, None)
+            .spot_trade(&"BTCUSDT".parse().unwrap(), order.order_id, None)
             .await
             .unwrap();
         client
-            .spot_trades(&"BTCUSDT".into(), None, None, None, None, None, None)
+            .spot_trades(
+                &"BTCUSDT".parse().unwrap(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
             .await
             .unwrap();
     }
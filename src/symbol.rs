@@ -0,0 +1,393 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Deref;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    http::{
+        client::{Client, ClientResult},
+        error::ClientError,
+    },
+    market::{ExchangeInfo, SymbolInfo},
+};
+
+const MAX_NAME_LENGTH: usize = 20;
+
+impl Client {
+    pub async fn symbol_registry(&self) -> ClientResult<SymbolRegistry> {
+        Ok(self.exchange_infos(None).await?.into())
+    }
+}
+
+fn validate(kind: &str, value: &str, extra: &[char]) -> ClientResult<()> {
+    let valid = (1..=MAX_NAME_LENGTH).contains(&value.len())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || extra.contains(&c));
+
+    match valid {
+        true => Ok(()),
+        false => Err(ClientError::InvalidParameter(format!(
+            "Invalid {} {:?}",
+            kind, value
+        ))),
+    }
+}
+
+// Trading pair such as BTCUSDT, matching `^[A-Z0-9-_.]{1,20}$` when built
+// locally. Names returned by Binance are taken as they are.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Symbol(String);
+
+impl Symbol {
+    pub fn new(value: impl Into<String>) -> ClientResult<Self> {
+        let value = value.into();
+        validate("Symbol", &value, &['-', '_', '.'])?;
+
+        Ok(Self(value))
+    }
+
+    // Name of the pair as Binance builds it, which is not guaranteed to be
+    // listed, see `SymbolRegistry::find`
+    pub fn from_assets(base: &Asset, quote: &Asset) -> Self {
+        Self(format!("{}{}", base, quote))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// Coin such as BTC, matching `^[A-Z0-9]{1,20}$` when built locally
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Asset(String);
+
+impl Asset {
+    pub fn new(value: impl Into<String>) -> ClientResult<Self> {
+        let value = value.into();
+        validate("Asset", &value, &[])?;
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Symbol {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Symbol {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Symbol {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> ClientResult<Self> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<&str> for Symbol {
+    type Error = ClientError;
+
+    fn try_from(value: &str) -> ClientResult<Self> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for Symbol {
+    type Error = ClientError;
+
+    fn try_from(value: String) -> ClientResult<Self> {
+        Self::new(value)
+    }
+}
+
+impl From<Symbol> for String {
+    fn from(value: Symbol) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl Deref for Asset {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Asset {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Asset {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Asset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Asset {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> ClientResult<Self> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<&str> for Asset {
+    type Error = ClientError;
+
+    fn try_from(value: &str) -> ClientResult<Self> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for Asset {
+    type Error = ClientError;
+
+    fn try_from(value: String) -> ClientResult<Self> {
+        Self::new(value)
+    }
+}
+
+impl From<Asset> for String {
+    fn from(value: Asset) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq<str> for Asset {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Asset {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+// Symbols of an exchange info indexed by name and by base/quote assets
+#[derive(Debug, Clone, Default)]
+pub struct SymbolRegistry {
+    symbols: HashMap<Symbol, SymbolInfo>,
+    pairs: HashMap<Asset, HashMap<Asset, Symbol>>,
+}
+
+impl SymbolRegistry {
+    pub fn new(symbols: Vec<SymbolInfo>) -> Self {
+        let mut registry = Self::default();
+
+        for info in symbols {
+            registry
+                .pairs
+                .entry(info.base_asset.clone())
+                .or_default()
+                .insert(info.quote_asset.clone(), info.symbol.clone());
+            registry.symbols.insert(info.symbol.clone(), info);
+        }
+
+        registry
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.symbols.contains_key(symbol)
+    }
+
+    pub fn get(&self, symbol: &str) -> Option<&SymbolInfo> {
+        self.symbols.get(symbol)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SymbolInfo> {
+        self.symbols.values()
+    }
+
+    pub fn base_asset(&self, symbol: &str) -> Option<&Asset> {
+        self.get(symbol).map(|v| &v.base_asset)
+    }
+
+    pub fn quote_asset(&self, symbol: &str) -> Option<&Asset> {
+        self.get(symbol).map(|v| &v.quote_asset)
+    }
+
+    // Base and quote assets of a listed symbol
+    pub fn assets(&self, symbol: &str) -> Option<(&Asset, &Asset)> {
+        self.get(symbol).map(|v| (&v.base_asset, &v.quote_asset))
+    }
+
+    // Symbol trading `base` against `quote`
+    pub fn find(&self, base: &str, quote: &str) -> Option<&SymbolInfo> {
+        let symbol = self.pairs.get(base)?.get(quote)?;
+
+        self.get(symbol)
+    }
+
+    // Symbol trading the two assets in either direction, along with whether
+    // `first` is its base asset
+    pub fn find_pair(&self, first: &str, second: &str) -> Option<(&SymbolInfo, bool)> {
+        match self.find(first, second) {
+            Some(info) => Some((info, true)),
+            None => self.find(second, first).map(|info| (info, false)),
+        }
+    }
+
+    // Symbols having `asset` as base or quote asset
+    pub fn with_asset<'a>(&'a self, asset: &'a str) -> impl Iterator<Item = &'a SymbolInfo> {
+        self.iter()
+            .filter(move |v| v.base_asset == asset || v.quote_asset == asset)
+    }
+}
+
+impl From<Vec<SymbolInfo>> for SymbolRegistry {
+    fn from(value: Vec<SymbolInfo>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<ExchangeInfo> for SymbolRegistry {
+    fn from(value: ExchangeInfo) -> Self {
+        Self::new(value.symbols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Asset, Symbol, SymbolRegistry};
+    use crate::http::client::tests::client;
+    use crate::market::SymbolInfo;
+
+    #[test]
+    fn test_symbol_asset_validation() {
+        let symbol: Symbol = "BTCUSDT".parse().unwrap();
+        assert_eq!(symbol, "BTCUSDT");
+        assert_eq!(symbol.len(), 7);
+        assert!(Symbol::new("1000SATS-USDT_1.0").is_ok());
+
+        for value in [
+            "",
+            "btcusdt",
+            "BTC USDT",
+            "BTC/USDT",
+            "ABCDEFGHIJKLMNOPQRSTU",
+        ] {
+            assert!(value.parse::<Symbol>().is_err(), "{:?}", value);
+        }
+
+        let base = Asset::new("BTC").unwrap();
+        let quote: Asset = "USDT".parse().unwrap();
+        assert_eq!(Symbol::from_assets(&base, &quote), "BTCUSDT");
+
+        for value in ["", "btc", "BTC-", "BTC.B"] {
+            assert!(value.parse::<Asset>().is_err(), "{:?}", value);
+        }
+
+        let json_data = r#"["BTCUSDT","ETHBTC"]"#;
+        let symbols: Vec<Symbol> = serde_json::from_str(json_data).unwrap();
+        assert_eq!(symbols[1].as_str(), "ETHBTC");
+        assert_eq!(serde_json::to_string(&symbols).unwrap(), json_data);
+    }
+
+    #[test]
+    fn test_symbol_registry() {
+        let json_data = r#"{"symbol":"ETHBTC","status":"TRADING","baseAsset":"ETH","baseAssetPrecision":8,"quoteAsset":"BTC","quotePrecision":8,"quoteAssetPrecision":8,"baseCommissionPrecision":8,"quoteCommissionPrecision":8,"orderTypes":["LIMIT","LIMIT_MAKER","MARKET","STOP_LOSS","STOP_LOSS_LIMIT","TAKE_PROFIT","TAKE_PROFIT_LIMIT"],"icebergAllowed":true,"ocoAllowed":true,"otoAllowed":true,"quoteOrderQtyMarketAllowed":true,"allowTrailingStop":false,"cancelReplaceAllowed":false,"isSpotTradingAllowed":true,"isMarginTradingAllowed":true,"filters":[],"permissions":[],"permissionSets":[["SPOT","MARGIN"]],"defaultSelfTradePreventionMode":"NONE","allowedSelfTradePreventionModes":["NONE"]}"#;
+        let info: SymbolInfo = serde_json::from_str(json_data).unwrap();
+
+        let mut symbols = vec![info.clone(), info.clone(), info];
+        for (info, (symbol, base, quote)) in symbols.iter_mut().zip([
+            ("ETHBTC", "ETH", "BTC"),
+            ("BTCUSDT", "BTC", "USDT"),
+            ("ETHUSDT", "ETH", "USDT"),
+        ]) {
+            info.symbol = symbol.parse().unwrap();
+            info.base_asset = base.parse().unwrap();
+            info.quote_asset = quote.parse().unwrap();
+        }
+
+        let registry = SymbolRegistry::new(symbols);
+        assert_eq!(registry.len(), 3);
+        assert!(registry.contains("BTCUSDT"));
+        assert!(!registry.contains("USDTBTC"));
+        assert_eq!(registry.base_asset("BTCUSDT").unwrap(), "BTC");
+        assert_eq!(registry.quote_asset("ETHBTC").unwrap(), "BTC");
+        assert_eq!(registry.assets("ETHUSDT").unwrap().0, "ETH");
+
+        assert_eq!(registry.find("ETH", "BTC").unwrap().symbol, "ETHBTC");
+        assert!(registry.find("BTC", "ETH").is_none());
+
+        let (info, is_base) = registry.find_pair("BTC", "ETH").unwrap();
+        assert_eq!((info.symbol.as_str(), is_base), ("ETHBTC", false));
+        assert!(registry.find_pair("BTC", "BNB").is_none());
+
+        let mut eth: Vec<_> = registry
+            .with_asset("ETH")
+            .map(|v| v.symbol.as_str())
+            .collect();
+        eth.sort();
+        assert_eq!(eth, ["ETHBTC", "ETHUSDT"]);
+    }
+
+    #[tokio::test]
+    async fn test_client_symbol_registry() {
+        let client = client();
+        let registry = client.symbol_registry().await.unwrap();
+        assert_eq!(registry.base_asset("BTCUSDT").unwrap(), "BTC");
+        assert!(registry.find("BTC", "USDT").is_some());
+    }
+}
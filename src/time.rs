@@ -1,4 +1,18 @@
-use std::time::Duration;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::http::client::{Client, ClientResult};
+use crate::http::error::ClientError;
+
+const DEFAULT_SYNC_INTERVAL: Duration = Duration::from_secs(300);
+
+pub const MAX_RECV_WINDOW: Duration = Duration::from_millis(60000);
 
 pub fn timestamp() -> Duration {
     use std::time::SystemTime;
@@ -7,3 +21,530 @@ pub fn timestamp() -> Duration {
 
     SystemTime::now().duration_since(earlier).expect(msg)
 }
+
+// Milliseconds with up to three decimals, anything below a microsecond is
+// truncated as Binance does not accept it
+pub(crate) fn recv_window_param(value: Duration) -> ClientResult<String> {
+    let micros = value.as_micros();
+
+    if micros == 0 || value > MAX_RECV_WINDOW {
+        let msg = format!("recvWindow must be within 1us and {:?}", MAX_RECV_WINDOW);
+        return Err(ClientError::InvalidParameter(msg));
+    }
+
+    let (millis, fraction) = (micros / 1000, micros % 1000);
+
+    match fraction {
+        0 => Ok(millis.to_string()),
+        _ => {
+            let fraction = format!("{:03}", fraction);
+            Ok(format!("{}.{}", millis, fraction.trim_end_matches('0')))
+        }
+    }
+}
+
+// Unit of the times in response bodies, requested with `X-MBX-TIME-UNIT`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TimeUnit {
+    #[default]
+    Millisecond,
+    Microsecond,
+}
+
+impl TimeUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Millisecond => "MILLISECOND",
+            Self::Microsecond => "MICROSECOND",
+        }
+    }
+
+    fn per_micro(&self) -> i64 {
+        match self {
+            Self::Millisecond => 1000,
+            Self::Microsecond => 1,
+        }
+    }
+}
+
+thread_local! {
+    static DECODE_TIME_UNIT: Cell<TimeUnit> = const { Cell::new(TimeUnit::Millisecond) };
+}
+
+// Deserializes timestamps inside `f` with `unit`, since the raw numbers do
+// not tell milliseconds from microseconds
+pub(crate) fn with_time_unit<T>(unit: TimeUnit, f: impl FnOnce() -> T) -> T {
+    struct Restore(TimeUnit);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DECODE_TIME_UNIT.with(|v| v.set(self.0));
+        }
+    }
+
+    let _restore = Restore(DECODE_TIME_UNIT.with(|v| v.replace(unit)));
+
+    f()
+}
+
+// Time since UNIX EPOCH as Binance sends it, kept in its original unit so
+// that it serializes back unchanged. Comparisons are made on the instant.
+#[derive(Debug, Clone, Copy)]
+pub struct Timestamp {
+    value: i64,
+    unit: TimeUnit,
+}
+
+impl Timestamp {
+    pub fn from_millis(value: i64) -> Self {
+        Self {
+            value,
+            unit: TimeUnit::Millisecond,
+        }
+    }
+
+    pub fn from_micros(value: i64) -> Self {
+        Self {
+            value,
+            unit: TimeUnit::Microsecond,
+        }
+    }
+
+    pub fn now() -> Self {
+        SystemTime::now().into()
+    }
+
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    // Rounded down for microsecond timestamps
+    pub fn as_millis(&self) -> i64 {
+        self.as_micros().div_euclid(1000)
+    }
+
+    pub fn as_micros(&self) -> i64 {
+        self.value.saturating_mul(self.unit.per_micro())
+    }
+
+    pub fn to_system_time(&self) -> SystemTime {
+        let micros = self.as_micros();
+
+        match micros >= 0 {
+            true => SystemTime::UNIX_EPOCH + Duration::from_micros(micros as u64),
+            false => SystemTime::UNIX_EPOCH - Duration::from_micros(micros.unsigned_abs()),
+        }
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_micros() == other.as_micros()
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_micros().cmp(&other.as_micros())
+    }
+}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_micros().hash(state);
+    }
+}
+
+// The raw value, which Binance accepts in either unit for time parameters
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(value: SystemTime) -> Self {
+        let micros = match value.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(v) => v.as_micros() as i64,
+            Err(e) => -(e.duration().as_micros() as i64),
+        };
+
+        Self::from_micros(micros)
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(value: Timestamp) -> Self {
+        value.to_system_time()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        Self::from_micros(value.timestamp_micros())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(value: Timestamp) -> Self {
+        value.to_system_time().into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<::time::OffsetDateTime> for Timestamp {
+    fn from(value: ::time::OffsetDateTime) -> Self {
+        Self::from_micros((value.unix_timestamp_nanos() / 1000) as i64)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for ::time::OffsetDateTime {
+    fn from(value: Timestamp) -> Self {
+        value.to_system_time().into()
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.value)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = i64::deserialize(deserializer)?;
+        let unit = DECODE_TIME_UNIT.with(|v| v.get());
+
+        Ok(Self { value, unit })
+    }
+}
+
+// Source of the local time since UNIX EPOCH, replaceable for tests
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Duration;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        timestamp()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ClockState {
+    offset_millis: i64,
+    round_trip: Duration,
+    synced_at: Duration,
+}
+
+// Keeps the offset between the local clock and the server clock, measured
+// through `server_time`, and applies it to the timestamp of signed requests
+pub struct ClockSync {
+    clock: Arc<dyn Clock>,
+    interval: Duration,
+    state: Mutex<Option<ClockState>>,
+}
+
+impl Default for ClockSync {
+    fn default() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl ClockSync {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_clock<C>(clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        Self {
+            clock: Arc::new(clock),
+            interval: DEFAULT_SYNC_INTERVAL,
+            state: Mutex::new(None),
+        }
+    }
+
+    pub fn set_interval(mut self, value: Duration) -> Self {
+        self.interval = value;
+
+        self
+    }
+
+    // Milliseconds to add to the local clock to get the server time
+    pub fn offset_millis(&self) -> Option<i64> {
+        self.state().map(|v| v.offset_millis)
+    }
+
+    pub fn round_trip(&self) -> Option<Duration> {
+        self.state().map(|v| v.round_trip)
+    }
+
+    pub fn is_synced(&self) -> bool {
+        match self.state() {
+            Some(state) => self.clock.now().saturating_sub(state.synced_at) < self.interval,
+            None => false,
+        }
+    }
+
+    // Forces a new measurement before the next signed request
+    pub fn invalidate(&self) {
+        *self.state.lock().unwrap() = None;
+    }
+
+    pub(crate) fn now(&self) -> Duration {
+        self.clock.now()
+    }
+
+    pub(crate) fn timestamp(&self) -> Duration {
+        let now = self.clock.now();
+
+        match self.offset_millis() {
+            Some(offset) if offset >= 0 => now + Duration::from_millis(offset as u64),
+            Some(offset) => now.saturating_sub(Duration::from_millis(offset.unsigned_abs())),
+            None => now,
+        }
+    }
+
+    // Assumes the server read its clock half way through the round trip
+    pub(crate) fn update(&self, sent_at: Duration, received_at: Duration, server_time: i64) {
+        let round_trip = received_at.saturating_sub(sent_at);
+        let local_time = (sent_at + round_trip / 2).as_millis();
+
+        let state = ClockState {
+            offset_millis: server_time - local_time as i64,
+            round_trip,
+            synced_at: received_at,
+        };
+
+        *self.state.lock().unwrap() = Some(state);
+    }
+
+    fn state(&self) -> Option<ClockState> {
+        *self.state.lock().unwrap()
+    }
+}
+
+impl Client {
+    pub fn clock_sync(&self) -> Option<&ClockSync> {
+        self.clock_sync.as_ref()
+    }
+
+    // Measures the offset to the server clock, a no-op without `ClockSync`
+    pub async fn sync_clock(&self) -> ClientResult<()> {
+        let clock_sync = match &self.clock_sync {
+            Some(value) => value,
+            None => return Ok(()),
+        };
+
+        let sent_at = clock_sync.now();
+        let server_time = self.server_time().await?;
+        let received_at = clock_sync.now();
+
+        clock_sync.update(sent_at, received_at, server_time.server_time.as_millis());
+
+        Ok(())
+    }
+
+    // Time since UNIX EPOCH to stamp signed requests with, resynchronised
+    // with the server when the last measurement is stale
+    pub(crate) async fn timestamp(&self) -> ClientResult<Duration> {
+        let clock_sync = match &self.clock_sync {
+            Some(value) => value,
+            None => return Ok(timestamp()),
+        };
+
+        // Boxed since `sync_clock` sends a request itself
+        if !clock_sync.is_synced() {
+            Box::pin(self.sync_clock()).await?;
+        }
+
+        Ok(clock_sync.timestamp())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+
+    use super::{recv_window_param, with_time_unit, Clock, ClockSync, TimeUnit, Timestamp};
+
+    use crate::http::client::{tests::mock_server, Client};
+
+    #[derive(Clone, Default)]
+    struct ManualClock(Arc<AtomicU64>);
+
+    impl ManualClock {
+        fn set(&self, millis: u64) {
+            self.0.store(millis, Ordering::SeqCst);
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now(&self) -> Duration {
+            Duration::from_millis(self.0.load(Ordering::SeqCst))
+        }
+    }
+
+    #[test]
+    fn test_clock_sync_offset() {
+        let clock = ManualClock::default();
+        let clock_sync = ClockSync::with_clock(clock.clone()).set_interval(Duration::from_secs(60));
+
+        assert!(!clock_sync.is_synced());
+
+        clock_sync.update(
+            Duration::from_millis(1_000_000),
+            Duration::from_millis(1_000_200),
+            1_000_600,
+        );
+        assert_eq!(clock_sync.offset_millis(), Some(500));
+        assert_eq!(clock_sync.round_trip(), Some(Duration::from_millis(200)));
+
+        clock.set(1_001_000);
+        assert!(clock_sync.is_synced());
+        assert_eq!(clock_sync.timestamp(), Duration::from_millis(1_001_500));
+
+        clock.set(1_060_200);
+        assert!(!clock_sync.is_synced());
+
+        clock_sync.update(
+            Duration::from_millis(1_060_200),
+            Duration::from_millis(1_060_200),
+            1_059_200,
+        );
+        assert_eq!(clock_sync.timestamp(), Duration::from_millis(1_059_200));
+    }
+
+    #[test]
+    fn test_recv_window_param() {
+        let param = |v| recv_window_param(v).ok();
+
+        assert_eq!(param(Duration::from_millis(5000)), Some("5000".into()));
+        assert_eq!(
+            param(Duration::from_micros(6000346)),
+            Some("6000.346".into())
+        );
+        assert_eq!(param(Duration::from_micros(1500)), Some("1.5".into()));
+        assert_eq!(param(Duration::from_nanos(2_000_999)), Some("2".into()));
+        assert_eq!(param(Duration::from_secs(60)), Some("60000".into()));
+        assert_eq!(param(Duration::from_micros(60_000_001)), None);
+        assert_eq!(param(Duration::from_nanos(999)), None);
+    }
+
+    #[tokio::test]
+    async fn test_signed_request_uses_server_time() {
+        let clock = ManualClock::default();
+        clock.set(1_000_000);
+
+        let (base_url, requests) = mock_server(vec![
+            (200, r#"{"serverTime":1005000}"#.into()),
+            (200, "[]".into()),
+            (
+                400,
+                r#"{"code":-1021,"msg":"Timestamp for this request is outside of the recvWindow."}"#
+                    .into(),
+            ),
+        ])
+        .await;
+
+        let client = Client::builder()
+            .set_base_url(base_url)
+            .set_api_key("key".into())
+            .set_secret_key("secret".into())
+            .set_clock_sync(ClockSync::with_clock(clock))
+            .build()
+            .unwrap();
+
+        client.spot_open_orders(None, None).await.unwrap();
+        assert_eq!(client.clock_sync().unwrap().offset_millis(), Some(5000));
+
+        assert!(client.spot_open_orders(None, None).await.is_err());
+        assert!(!client.clock_sync().unwrap().is_synced());
+
+        let requests = requests.await.unwrap();
+        assert!(requests[0].starts_with("GET /api/v3/time "));
+        assert!(requests[1].contains("timestamp=1005000&signature="));
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let millis = Timestamp::from_millis(1_700_000_000_123);
+        let micros = Timestamp::from_micros(1_700_000_000_123_456);
+
+        assert_eq!(millis.as_micros(), 1_700_000_000_123_000);
+        assert_eq!(micros.as_millis(), 1_700_000_000_123);
+        assert!(millis < micros);
+        assert_eq!(millis, Timestamp::from_micros(1_700_000_000_123_000));
+        assert_eq!(Timestamp::from_millis(-1).as_micros(), -1000);
+        assert_eq!(Timestamp::from_micros(-1).as_millis(), -1);
+
+        let time = SystemTime::UNIX_EPOCH + Duration::from_micros(1_700_000_000_123_456);
+        assert_eq!(micros.to_system_time(), time);
+        assert_eq!(Timestamp::from(time), micros);
+        assert_eq!(micros.to_string(), "1700000000123456");
+
+        #[cfg(feature = "chrono")]
+        {
+            let value: chrono::DateTime<chrono::Utc> = micros.into();
+            assert_eq!(value.timestamp_micros(), 1_700_000_000_123_456);
+            assert_eq!(Timestamp::from(value), micros);
+        }
+
+        #[cfg(feature = "time")]
+        {
+            let value: ::time::OffsetDateTime = millis.into();
+            assert_eq!(value.unix_timestamp(), 1_700_000_000);
+            assert_eq!(Timestamp::from(value), millis);
+        }
+    }
+
+    #[test]
+    fn test_timestamp_serde() {
+        let json_data = "[1700000000123456,-1]";
+
+        let values: Vec<Timestamp> = serde_json::from_str(json_data).unwrap();
+        assert_eq!(values[0].unit(), TimeUnit::Millisecond);
+
+        let values: Vec<Timestamp> = with_time_unit(TimeUnit::Microsecond, || {
+            serde_json::from_str(json_data).unwrap()
+        });
+        assert_eq!(values[0].unit(), TimeUnit::Microsecond);
+        assert_eq!(values[0].as_millis(), 1_700_000_000_123);
+        assert_eq!(serde_json::to_string(&values).unwrap(), json_data);
+    }
+
+    #[tokio::test]
+    async fn test_client_time_unit() {
+        let (base_url, _) =
+            mock_server(vec![(200, r#"{"serverTime":1005000123456}"#.into())]).await;
+
+        let client = Client::builder()
+            .set_base_url(base_url)
+            .set_time_unit(TimeUnit::Microsecond)
+            .build()
+            .unwrap();
+
+        let server_time = client.server_time().await.unwrap();
+        assert_eq!(server_time.server_time.unit(), TimeUnit::Microsecond);
+        assert_eq!(server_time.server_time.as_millis(), 1_005_000_123);
+    }
+}